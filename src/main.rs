@@ -9,8 +9,11 @@ fn main() {
     all_places_patterns_can_be_used();
     refutability_whether_a_pattern_might_fail_to_match();
     pattern_syntax();
+    practical_patterns();
 }
 
+//`if let None` below is intentionally used instead of `is_none()` to show `if let`.
+#[allow(clippy::redundant_pattern_matching)]
 fn all_places_patterns_can_be_used() {
 
     //The `match` keyword can be used to match patterns, but it must be exhaustive. Exhaustiveness
@@ -92,6 +95,8 @@ fn refutability_whether_a_pattern_might_fail_to_match() {
 
 }
 
+//Some of the matches below only have a single arm on purpose to show the syntax.
+#[allow(clippy::match_single_binding)]
 fn pattern_syntax() {
     //This section is a list of all pattern and the recommendation for when to use each.
 
@@ -120,11 +125,6 @@ fn pattern_syntax() {
         _ => println!("unknown"),
     }
 
-    struct Triangle {
-        base: isize,
-        height: isize,
-    }
-
     let triangle = Triangle{
         base: 5,
         height: 10,
@@ -150,13 +150,6 @@ fn pattern_syntax() {
         _ => println!("other triangle"),
     }
 
-    struct Screen {
-        size: isize,
-        x: isize,
-        y: isize,
-        t: Triangle,
-    }
-
     let screen = Screen {
         size: 10,
         x: 0,
@@ -229,3 +222,3971 @@ fn pattern_syntax() {
     }
 
 }
+
+struct Triangle {
+    base: isize,
+    height: isize,
+}
+
+struct Screen {
+    size: isize,
+    x: isize,
+    y: isize,
+    t: Triangle,
+}
+
+fn practical_patterns() {
+    //This section is not from the book. It is a collection of more practical examples of where
+    // patterns are useful.
+
+    //A buffer with an integer record, a text record and a record with an unknown tag.
+    let buf = [
+        0x01, 0x00, 0x08, 0, 0, 0, 0, 0, 0, 0x01, 0x2C,
+        0x02, 0x00, 0x02, b'h', b'i',
+        0x7F, 0x00, 0x01, 0xAA,
+    ];
+    if let Ok(records) = parse_tlv(&buf) {
+        for record in records {
+            match record {
+                TlvRecord::Integer { tag, value } => println!("tlv {tag} integer: {value}"),
+                TlvRecord::Text { tag, value } => println!("tlv {tag} text: {value}"),
+                TlvRecord::Bytes { tag, data } => println!("tlv {tag} bytes: {data:?}"),
+                TlvRecord::Unknown { tag, length, data } => {
+                    println!("tlv {tag} unknown ({length} bytes): {data:?}")
+                }
+            }
+        }
+    }
+    println!("truncated tlv: {:?}", parse_tlv(&[0x01, 0x00]));
+    //Splitting a line into words gives a slice that can be matched on by its length.
+    for line in ["help", "add 2 3", "echo hello there", "remove x", ""] {
+        let parts: Vec<&str> = line.split_whitespace().collect();
+        println!("run_command({line:?}): {:?}", run_command(&parts));
+    }
+
+    //A text frame "hi" followed by a close frame with code 1000 and reason "bye".
+    let frames = [0x81, 0x02, b'h', b'i', 0x88, 0x05, 0x03, 0xE8, b'b', b'y', b'e'];
+    let mut remaining: &[u8] = &frames;
+    while !remaining.is_empty() {
+        match parse_frame(remaining) {
+            Ok((frame, rest)) => {
+                match frame {
+                    WsFrame::Text(text) => println!("ws text: {text}"),
+                    WsFrame::Binary(data) => println!("ws binary: {data:?}"),
+                    WsFrame::Ping(data) => println!("ws ping: {data:?}"),
+                    WsFrame::Pong(data) => println!("ws pong: {data:?}"),
+                    WsFrame::Close(code, reason) => println!("ws close: {code} {reason}"),
+                }
+                remaining = rest;
+            }
+            Err(e) => {
+                println!("ws error: {e:?}");
+                break;
+            }
+        }
+    }
+    if let Err(WsError::UnknownOpcode(opcode)) = parse_frame(&[0x83, 0x00]) {
+        println!("ws unknown opcode: {opcode:#x}");
+    }
+    if let Err(WsError::Unsupported) = parse_frame(&[0x81, 0x82, 1, 2, 3, 4, 5, 6]) {
+        println!("ws masked frames are unsupported");
+    }
+
+    //Ranges can cover every value of a `u8` so that no `_` arm is needed.
+    for octet in [0, 127, 128, 191, 192, 223, 224, 239, 240, 255] {
+        println!("ip class of {octet}: {}", ip_class(octet));
+    }
+
+    println!("label_signs: {:?}", label_signs(&[-1, 0, 1]));
+
+    let builders = [
+        ConfigBuilder::default(),
+        ConfigBuilder { host: Some("   ".into()), ..Default::default() },
+        ConfigBuilder { host: Some("localhost".into()), port: Some(3000), workers: Some(4) },
+        ConfigBuilder { host: Some("localhost".into()), workers: Some(0), ..Default::default() },
+        ConfigBuilder { host: Some("localhost".into()), ..Default::default() },
+    ];
+    for builder in builders {
+        match builder.build() {
+            Ok(Config { host, port, workers }) => {
+                println!("config: host {host} port {port} workers {workers}")
+            }
+            Err(errors) => println!("config errors: {errors:?}"),
+        }
+    }
+
+    //Filters can be built up out of smaller filters and then matched recursively.
+    let triangles = [
+        Triangle { base: 3, height: 1 },
+        Triangle { base: 5, height: 2 },
+        Triangle { base: 8, height: 3 },
+        Triangle { base: 12, height: 4 },
+    ];
+    let filters = [
+        QueryOp::Eq(5),
+        QueryOp::NotEq(5),
+        QueryOp::Lt(8),
+        QueryOp::Gt(5),
+        QueryOp::In(vec![3, 12]),
+        QueryOp::And(Box::new(QueryOp::Gt(3)), Box::new(QueryOp::Lt(12))),
+        QueryOp::Or(
+            Box::new(QueryOp::Eq(3)),
+            Box::new(QueryOp::And(
+                Box::new(QueryOp::Gt(5)),
+                Box::new(QueryOp::Not(Box::new(QueryOp::Eq(12)))),
+            )),
+        ),
+    ];
+    for filter in &filters {
+        let heights: Vec<isize> = triangles
+            .iter()
+            .filter(|t| matches(&t.base, filter))
+            .map(|t| t.height)
+            .collect();
+        println!("triangles matching filter: {heights:?}");
+    }
+
+    for (a, b) in [(false, false), (false, true), (true, false), (true, true)] {
+        println!("{a} xor {b}: {}", xor_table(a, b));
+    }
+
+    let small = Triangle { base: 2, height: 3 };
+    let large = Triangle { base: 4, height: 6 };
+    let other = Triangle { base: 4, height: 5 };
+    println!("2x3 similar to 4x6: {}", similar(&small, &large));
+    println!("2x3 similar to 4x5: {}", similar(&small, &other));
+
+    for x in [3, 7, 13, 18, 25] {
+        println!(
+            "{x}: overlapping {} correct {}",
+            demonstrate_arm_ordering(x),
+            correct_ordering(x)
+        );
+    }
+
+    println!("csv: {:?}", parse_csv_line("a,b,c"));
+    println!("csv: {:?}", parse_csv_line(r#"name,"Smith, John","say ""hi""""#));
+
+    println!("extract_or_default: {}", extract_or_default(Some("x".to_string()), "default"));
+    println!("extract_or_default: {}", extract_or_default(None, "default"));
+
+    println!("handle Ok: {:?}", handle(Ok(5)));
+    println!("handle NotFound: {:?}", handle::<i32>(Err(AppError::NotFound)));
+    println!("handle Invalid: {:?}", handle::<i32>(Err(AppError::Invalid("bad input".into()))));
+    println!("handle Timeout: {:?}", handle::<i32>(Err(AppError::Timeout)));
+
+    let mut counts: Vec<(&str, usize)> = word_counts("a b a c a").into_iter().collect();
+    counts.sort();
+    println!("word_counts: {counts:?}");
+    let mut counts: Vec<(&str, usize)> = word_counts_entry_match("a b a c a").into_iter().collect();
+    counts.sort();
+    println!("word_counts_entry_match: {counts:?}");
+
+    let empty: Vec<i32> = Vec::new();
+    let mut full = Vec::with_capacity(2);
+    full.extend([1, 2]);
+    let mut has_room = Vec::with_capacity(10);
+    has_room.push(1);
+    println!("vec states: {} {} {}", vec_state(&empty), vec_state(&full), vec_state(&has_room));
+
+    println!("get_mode: {}", get_mode());
+    println!("get_mode_from present: {}", get_mode_from(Ok("production".into())));
+    println!("get_mode_from absent: {}", get_mode_from(Err(std::env::VarError::NotPresent)));
+
+    println!("simple_checksum: {}", simple_checksum(&[1, 0, 2, 0, 3]));
+    println!("simple_checksum wrapping: {}", simple_checksum(&[200, 0, 100]));
+
+    for x in [0.0, 0.5, 50.0, 50000.0, 5e9] {
+        println!("magnitude_bucket({x}): {}", magnitude_bucket(x));
+    }
+
+    println!("CLASSIFIED: {CLASSIFIED:?}");
+
+    let mut screen = Screen::square(10);
+    for cmd in [ScreenCmd::Resize(20), ScreenCmd::MoveBy(3, -2), ScreenCmd::MoveBy(1, 1)] {
+        apply_cmd(&mut screen, cmd);
+    }
+    println!("screen after commands: size {} x {} y {}", screen.size, screen.x, screen.y);
+    apply_cmd(&mut screen, ScreenCmd::Reset);
+    println!("screen after reset: size {} x {} y {}", screen.size, screen.x, screen.y);
+
+    for c in ['a', '3', ' ', '@'] {
+        println!("char_category({c:?}): {}", char_category(c));
+    }
+
+    let nested = Nested::List(vec![
+        Nested::Leaf(1),
+        Nested::List(vec![Nested::Leaf(2), Nested::List(vec![Nested::Leaf(3), Nested::Leaf(4)])]),
+        Nested::List(vec![]),
+        Nested::Leaf(5),
+    ]);
+    println!("flatten: {:?}", flatten(&nested));
+
+    //Both a `Box<str>` and a `String` deref to a `&str` so the same function works for either.
+    let boxed: Box<str> = "boxed".into();
+    let empty_boxed: Box<str> = "".into();
+    let owned = String::from("owned string");
+    let empty_owned = String::new();
+    println!(
+        "length_of: {} {} {} {}",
+        length_of(&boxed),
+        length_of(&empty_boxed),
+        length_of(&owned),
+        length_of(&empty_owned)
+    );
+
+    for code in [204, 301, 404, 100] {
+        println!("http_category: {}", http_category(code));
+    }
+
+    for c in ['a', '5', '?'] {
+        println!("to_morse({c:?}): {:?}", to_morse(c));
+    }
+
+    for word in ["racecar", "abba", "abc"] {
+        let chars: Vec<char> = word.chars().collect();
+        println!("is_palindrome({word}): {}", is_palindrome(&chars));
+    }
+
+    let items = [3, 1, 2];
+    println!("sorted asc: {:?}", sorted(&items, SortOrder::Asc));
+    println!("sorted desc: {:?}", sorted(&items, SortOrder::Desc));
+
+    println!("first negative: {:?}", find_first_negative_index(&[3, 0, -2, -5]));
+    println!("first negative: {:?}", find_first_negative_index(&[1, 2, 3]));
+
+    for bits in ["110", "10", "", "1001"] {
+        println!("divisible_by_three({bits:?}): {}", divisible_by_three(bits));
+    }
+
+    let mut screen = Screen { x: -3, y: 4, ..Screen::square(10) };
+    normalize_screen(&mut screen);
+    println!("normalized screen: x {} y {}", screen.x, screen.y);
+    let mut screen = Screen { x: 2, y: 5, ..Screen::square(10) };
+    normalize_screen(&mut screen);
+    println!("normalized screen: x {} y {}", screen.x, screen.y);
+
+    for s in ["", "   ", "hi"] {
+        println!("text_state({s:?}): {}", text_state(s));
+    }
+
+    let sextets: String = [0, 25, 26, 52, 62, 63, 64].into_iter().map(encode_sextet).collect();
+    println!("encode_sextet: {sextets}");
+
+    let allowlist = [1, 5, 9];
+    println!("is_allowed(5): {}", is_allowed(5, &allowlist));
+    println!("is_allowed(4): {}", is_allowed(4, &allowlist));
+
+    for s in ["30s", "5m", "2h", "10x"] {
+        println!("parse_duration({s:?}): {:?}", parse_duration(s));
+    }
+
+    let mut state = State::Idle;
+    let events = [
+        Event::Start,
+        Event::Resume,
+        Event::Pause,
+        Event::Resume,
+        Event::Stop,
+        Event::Start,
+    ];
+    for event in events {
+        let (next, valid) = transition(state, event);
+        println!("{state:?} + {event:?} -> {next:?} (valid: {valid})");
+        state = next;
+    }
+
+    for n in [5, 50, 500] {
+        println!("{}", describe_range_value(n));
+    }
+
+    println!("top_words: {:?}", top_words("the cat and the dog and the bird", 2));
+
+    println!("{}", describe_either(&Either::Left("hello".into())));
+    println!("{}", describe_either(&Either::Right(vec![1, 2, 3])));
+
+    let screen = Screen::square(10);
+    for (px, py) in [(5, 5), (10, 3), (11, 5)] {
+        println!("locate({px}, {py}): {:?}", screen.locate(px, py));
+    }
+
+    for (a, b) in [(Some(1), Some(2)), (Some(1), None), (None, Some(2)), (None, None)] {
+        let nested = combine_options_nested(a, b);
+        let tuple = combine_options(a, b);
+        println!("combine_options({a:?}, {b:?}): {tuple:?}, nested: {nested:?}");
+    }
+
+    for op in [CmpOp::Eq, CmpOp::Ne, CmpOp::Lt, CmpOp::Le, CmpOp::Gt, CmpOp::Ge] {
+        println!("compare({op:?}, 3, 5): {}", compare(op, 3, 5));
+    }
+
+    let cells = [
+        (Cell::Alive, 2),
+        (Cell::Alive, 3),
+        (Cell::Dead, 3),
+        (Cell::Alive, 4),
+        (Cell::Dead, 2),
+    ];
+    for (cell, neighbors) in cells {
+        println!("{cell:?} with {neighbors} neighbors -> {:?}", next_cell(cell, neighbors));
+    }
+
+    print!("{}", ascii_art(&Triangle { base: 5, height: 3 }));
+    println!("{}", ascii_art(&Triangle { base: 5, height: 500 }));
+
+    for v in [vec![], vec![1], vec![1, 2], vec![1, 2, 3]] {
+        println!("describe_vec({v:?}): {}", describe_vec(&v));
+    }
+
+    let expr = Bool::And(
+        Box::new(Bool::Lit(true)),
+        Box::new(Bool::Not(Box::new(Bool::Lit(false)))),
+    );
+    println!("eval_bool(And(Lit(true), Not(Lit(false)))): {}", eval_bool(&expr));
+    let expr = Bool::Or(Box::new(Bool::Lit(false)), Box::new(Bool::Lit(false)));
+    println!("eval_bool(Or(Lit(false), Lit(false))): {}", eval_bool(&expr));
+
+    println!("frame([4, 5, 6]): {:?}", frame(&[4, 5, 6]));
+    println!("frame([]): {:?}", frame(&[]));
+
+    for s in ["5", "5.5", "abc"] {
+        println!("number_kind({s:?}): {}", number_kind(s));
+    }
+
+    println!("decode_flags(0b101): {:?}", decode_flags(0b101));
+
+    let words = [
+        ("pattern", "pattern"),
+        ("pattern", "patern"),
+        ("match", "matches"),
+        ("rust", "python"),
+    ];
+    for (a, b) in words {
+        println!("suggest({a}, {b}): {}", suggest(edit_distance(a, b)));
+    }
+
+    for name in ["red", "green", "blue", "purple"] {
+        println!("Color::parse({name:?}): {:?}", Color::parse(name));
+    }
+
+    println!("dedup_adjacent: {:?}", dedup_adjacent(&[1, 1, 2, 2, 2, 3]));
+
+    let versions = [
+        (Version { major: 1, minor: 2, patch: 3 }, Version { major: 1, minor: 2, patch: 3 }),
+        (Version { major: 1, minor: 2, patch: 3 }, Version { major: 1, minor: 2, patch: 4 }),
+        (Version { major: 1, minor: 3, patch: 0 }, Version { major: 1, minor: 2, patch: 9 }),
+        (Version { major: 2, minor: 0, patch: 0 }, Version { major: 1, minor: 9, patch: 9 }),
+    ];
+    for (a, b) in &versions {
+        println!(
+            "{}.{}.{} vs {}.{}.{}: {:?}",
+            a.major,
+            a.minor,
+            a.patch,
+            b.major,
+            b.minor,
+            b.patch,
+            compare_versions(a, b)
+        );
+    }
+
+    println!("manual_sum(1..=10): {}", manual_sum(1..=10));
+
+    for income in [0, 10000, 10001, 40000, 40001, 100000] {
+        println!("tax_owed({income}): {}", tax_owed(income));
+    }
+
+    //The tuple that `minmax` returns can be taken apart right in the `if let`.
+    let data = [4, -2, 9, 0];
+    if let Some((lo, hi)) = minmax(&data) {
+        println!("minmax: lo {lo} hi {hi}");
+    }
+    if minmax(&[]).is_none() {
+        println!("minmax: no values");
+    }
+
+    let mut buffer = String::new();
+    let keys = [
+        Key::Char('h'),
+        Key::Char('i'),
+        Key::Char('x'),
+        Key::Backspace,
+        Key::Arrow(Dir::N),
+        Key::Arrow(Dir::S),
+        Key::Arrow(Dir::E),
+        Key::Arrow(Dir::W),
+        Key::Enter,
+        Key::Char('!'),
+    ];
+    for key in keys {
+        if let Key::Arrow(dir) = &key {
+            let name = match dir {
+                Dir::N => "up",
+                Dir::S => "down",
+                Dir::E => "right",
+                Dir::W => "left",
+            };
+            println!("handle_key ignoring arrow {name}");
+        }
+        handle_key(&mut buffer, key);
+    }
+    println!("handle_key buffer: {buffer:?}");
+
+    for e in [AppError::NotFound, AppError::Invalid("bad".into()), AppError::Timeout] {
+        println!("status_code({e:?}): {}", status_code(&e));
+    }
+
+    let values = [10, 20, 30];
+    println!("safe_index(1): {:?}", safe_index(&values, 1));
+    println!("safe_index(3): {:?}", safe_index(&values, 3));
+
+    let programs: [&[&str]; 4] = [
+        &["true", "false", "and"],
+        &["true", "false", "or"],
+        &["true", "true", "and", "false", "or"],
+        &["true", "and"],
+    ];
+    for tokens in programs {
+        println!("eval_keywords({tokens:?}): {:?}", eval_keywords(tokens));
+    }
+
+    let sorted_values = [1, 3, 5, 7];
+    println!("insert_position(5): {}", insert_position(&sorted_values, 5));
+    println!("insert_position(4): {}", insert_position(&sorted_values, 4));
+
+    let shapes = [
+        (
+            Shape::Triangle(Triangle { base: 3, height: 4 }),
+            Shape::Triangle(Triangle { base: 3, height: 4 }),
+        ),
+        (
+            Shape::Rectangle { width: 2, height: 5 },
+            Shape::Rectangle { width: 5, height: 2 },
+        ),
+        (Shape::Circle { radius: 3 }, Shape::Circle { radius: 4 }),
+        (Shape::Circle { radius: 3 }, Shape::Rectangle { width: 3, height: 3 }),
+    ];
+    for (a, b) in &shapes {
+        println!("congruent: {}", congruent(a, b));
+    }
+
+    println!("ends: {:?}", ends([10, 20, 30, 40, 50]));
+
+    for (hour, is_weekend) in [(3, false), (3, true), (10, true), (10, false), (20, false)] {
+        println!("schedule({hour}, {is_weekend}): {}", schedule(hour, is_weekend));
+    }
+
+    let Triangle { base, height } = adjusted(Triangle { base: 5, height: 10 }, 2);
+    println!("adjusted triangle: base {base} height {height}");
+
+    println!("before_delimiter: {}", before_delimiter("key=value", '='));
+    println!("before_delimiter: {}", before_delimiter("no delimiter", '='));
+
+    let configs = [migrate(ConfigV1::Name("server".into())), ConfigV2::Age(3)];
+    for config in &configs {
+        match config {
+            ConfigV2::Name(name) => println!("config v2 name: {name}"),
+            ConfigV2::Age(age) => println!("config v2 age: {age}"),
+        }
+    }
+
+    println!("medals: {:?}", medals(&[1, 2, 3, 4]));
+
+    let limit = std::time::Duration::from_secs(5);
+    for secs in [3, 5, 8] {
+        let elapsed = std::time::Duration::from_secs(secs);
+        println!("within_timeout({secs}s, 5s): {}", within_timeout(elapsed, limit));
+    }
+
+    for s in ["#ff0000", "00ff00", "#fff", "#12345678"] {
+        println!("parse_hex_color({s:?}): {:?}", parse_hex_color(s));
+    }
+
+    println!("first_even_square: {:?}", first_even_square(&[3, 5, 4, 6]));
+    println!("first_even_square: {:?}", first_even_square(&[1, 3, 5]));
+
+    println!("format_length: {}", format_length(2.0, System::Metric));
+    println!("format_length: {}", format_length(2.0, System::Imperial));
+
+    let inputs = [Ok("42".to_string()), Ok("forty two".to_string()), Err("missing".to_string())];
+    for raw in inputs {
+        println!("load_and_parse: {:?}", load_and_parse(raw));
+    }
+
+    let commands: [&[&str]; 4] = [
+        &["ls", ">", "out.txt"],
+        &["echo", "hi", ">>", "log.txt"],
+        &["sort", "<", "in.txt"],
+        &["ls", "-la"],
+    ];
+    for tokens in commands {
+        match parse_redirect(tokens) {
+            RedirectKind::None => println!("parse_redirect({tokens:?}): none"),
+            RedirectKind::Out(file) => println!("parse_redirect({tokens:?}): out to {file}"),
+            RedirectKind::Append(file) => println!("parse_redirect({tokens:?}): append to {file}"),
+            RedirectKind::In(file) => println!("parse_redirect({tokens:?}): in from {file}"),
+        }
+    }
+
+    let days = [
+        Weekday::Mon,
+        Weekday::Tue,
+        Weekday::Wed,
+        Weekday::Thu,
+        Weekday::Fri,
+        Weekday::Sat,
+        Weekday::Sun,
+    ];
+    let hours: Vec<u8> = days.iter().map(working_hours).collect();
+    println!("working_hours: {hours:?}");
+
+    let secret = caesar("Hello, World!", 3);
+    println!("caesar: {secret} -> {}", caesar(&secret, 26 - 3));
+
+    println!("distance: {:?}", distance(Some(((0, 0), (3, 4)))));
+    println!("distance: {:?}", distance(None));
+
+    let levels = [
+        Compression::None,
+        Compression::Fast,
+        Compression::Best,
+        Compression::Custom(6),
+        Compression::Custom(12),
+    ];
+    for level in &levels {
+        println!("strategy: {}", strategy(level));
+    }
+
+    for n in [200, 300, -1] {
+        println!("to_u8({n}): {:?}", to_u8(n));
+    }
+
+    let items = [
+        Json::Num(1.5),
+        Json::Str("two".into()),
+        Json::Null,
+        Json::Num(3.0),
+        Json::Bool(true),
+    ];
+    for item in &items {
+        match item {
+            Json::Null => println!("json null"),
+            Json::Bool(b) => println!("json bool {b}"),
+            Json::Num(n) => println!("json num {n}"),
+            Json::Str(s) => println!("json str {s}"),
+        }
+    }
+    println!("collect_numbers: {:?}", collect_numbers(&items));
+
+    println!("dot_product: {:?}", dot_product(&[1, 2, 3], &[4, 5, 6]));
+    println!("dot_product: {:?}", dot_product(&[1, 2], &[4, 5, 6]));
+
+    let errors = [
+        AppError::Invalid("nested parse failure".into()),
+        AppError::Invalid("missing field".into()),
+        AppError::Timeout,
+    ];
+    for e in &errors {
+        println!("root_message: {}", root_message(e));
+    }
+
+    for percent in [0, 50, 100, 150] {
+        println!("bar_fill({percent}%, 20): {}", bar_fill(percent, 20));
+    }
+
+    println!("safe_prefix: {}", safe_prefix("pattern", 3));
+    println!("safe_prefix: {}", safe_prefix("pattern", 30));
+    println!("safe_prefix: {}", safe_prefix("héllo", 2));
+
+    let mut triangles = vec![
+        Triangle { base: 6, height: 1 },
+        Triangle { base: 2, height: 5 },
+        Triangle { base: 4, height: 2 },
+    ];
+    for key in [TriangleKey::Base, TriangleKey::Height, TriangleKey::Area] {
+        sort_triangles(&mut triangles, key);
+        let sizes: Vec<(isize, isize)> = triangles.iter().map(|t| (t.base, t.height)).collect();
+        println!("sorted triangles: {sizes:?}");
+    }
+
+    for s in ["(())", "(()", ")("] {
+        println!("find_balanced({s:?}): {}", find_balanced(s));
+    }
+
+    for axis in ['x', 'y', 'z'] {
+        println!("reflect((3, 4), {axis:?}): {:?}", reflect((3, 4), axis));
+    }
+
+    println!("use_or_refetch: {}", use_or_refetch(CacheResult::Hit("cached"), "fetched"));
+    println!("use_or_refetch: {}", use_or_refetch(CacheResult::Stale("old"), "fetched"));
+    println!("use_or_refetch: {}", use_or_refetch(CacheResult::Miss, "fetched"));
+
+    println!("longest_run: {}", longest_run(&[1, 1, 1, 2, 2]));
+    println!("longest_run: {}", longest_run(&[]));
+
+    for arg in ["--verbose", "--no-color", "plain"] {
+        println!("parse_flag({arg:?}): {:?}", parse_flag(arg));
+    }
+
+    let v4 = std::net::IpAddr::V4(std::net::Ipv4Addr::new(127, 0, 0, 1));
+    let v6 = std::net::IpAddr::V6(std::net::Ipv6Addr::LOCALHOST);
+    println!("ip_version: {} {}", ip_version(v4), ip_version(v6));
+
+    for (die1, die2) in [(3, 4), (2, 2), (1, 3)] {
+        println!("roll_outcome({die1}, {die2}): {}", roll_outcome(die1, die2));
+    }
+
+    for cp in [0x41, 0xE9, 0xD800, 0x1F600, 0x110000] {
+        match codepoint_to_char(cp) {
+            Some(c) => println!("codepoint {cp:#x}: {c} ({})", codepoint_kind(cp)),
+            None => println!("codepoint {cp:#x}: not a char ({})", codepoint_kind(cp)),
+        }
+    }
+
+    for cents in [0, 500, 99, 1099] {
+        println!("amount_in_words({cents}): {}", amount_in_words(cents));
+    }
+
+    let list = LinkedNode {
+        value: 1,
+        next: Some(Box::new(LinkedNode {
+            value: 2,
+            next: Some(Box::new(LinkedNode { value: 3, next: None })),
+        })),
+    };
+    println!("sum_list: {}", sum_list(&list));
+
+    for hour in [11, 12, 17, 18, 24] {
+        println!("greeting({hour}): {}", greeting(hour));
+    }
+
+    let base = Screen::square(10);
+    let others = [
+        Screen { x: 20, y: 0, ..Screen::square(5) },
+        Screen { x: 10, y: 2, ..Screen::square(5) },
+        Screen { x: 8, y: 8, ..Screen::square(5) },
+    ];
+    for other in &others {
+        println!("overlap_kind: {}", overlap_kind(&base, other));
+    }
+
+    for c in ['w', 'S', 'a', 'D', 'q'] {
+        println!("parse_move({c:?}): {:?}", parse_move(c));
+    }
+
+    for days in [-2, -1, 0, 1, 5] {
+        println!("relative_label({days}): {}", relative_label(days));
+    }
+
+    let lines = ["HOST=localhost", "PORT=8080", "not a pair", "=missing key", "URL=a=b"];
+    let mut env: Vec<(String, String)> = parse_env_block(&lines).into_iter().collect();
+    env.sort();
+    println!("parse_env_block: {env:?}");
+
+    let shapes = [
+        Shape::Triangle(Triangle { base: 3, height: 4 }),
+        Shape::Rectangle { width: 2, height: 5 },
+        Shape::Circle { radius: 3 },
+    ];
+    for shape in &shapes {
+        println!("bounding_size: {:?}", bounding_size(shape));
+    }
+
+    let ballot = [Vote::Yes, Vote::No, Vote::Yes, Vote::Abstain, Vote::Yes];
+    println!("tally (yes, no, abstain): {:?}", tally(&ballot));
+
+    println!("isbn10_valid: {}", isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 5, 2]));
+    println!("isbn10_valid: {}", isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 5, 3]));
+    println!("isbn10_valid: {}", isbn10_valid(&[0, 3, 0]));
+
+    let data = std::sync::Mutex::new(42);
+    println!("with_locked: {}", with_locked(&data));
+
+    for (value, unit) in [(250, "ms"), (3, "s"), (2, "m"), (1, "h")] {
+        println!("timer_millis({value}, {unit:?}): {:?}", timer_millis(value, unit));
+    }
+
+    let screen = Screen::square(10);
+    println!("fits: {}", fits(&Shape::Rectangle { width: 8, height: 10 }, &screen));
+    println!("fits: {}", fits(&Shape::Circle { radius: 6 }, &screen));
+
+    let pairs = [
+        (Triangle { base: 5, height: 3 }, Triangle { base: 5, height: 9 }),
+        (Triangle { base: 2, height: 7 }, Triangle { base: 4, height: 7 }),
+        (Triangle { base: 1, height: 2 }, Triangle { base: 3, height: 4 }),
+    ];
+    for (a, b) in &pairs {
+        println!("triangle_relation: {}", triangle_relation(a, b));
+    }
+
+    for s in ["yes", "y", "no", "n", "perhaps"] {
+        println!("tristate({s:?}): {:?}", tristate(s));
+    }
+
+    println!("local_maxima: {:?}", local_maxima(&[1, 3, 2, 5, 4]));
+
+    println!("perm_string: {}", perm_string(true, false, true));
+    println!("perm_string: {}", perm_string(true, true, true));
+
+    let modes = [
+        Precision::Integer,
+        Precision::Fixed(2),
+        Precision::Fixed(20),
+        Precision::Scientific,
+    ];
+    for mode in modes {
+        println!("format_number: {}", format_number(1234.5678, mode));
+    }
+
+    for tile in [Tile::Wall, Tile::Floor, Tile::Goal] {
+        println!("{tile:?}: can_enter {} is_goal {}", can_enter(&tile), is_goal(&tile));
+    }
+
+    let (tx, rx) = std::sync::mpsc::channel();
+    let sender = std::thread::spawn(move || {
+        for v in [1, 2, 3] {
+            tx.send(v).unwrap();
+        }
+    });
+    println!("drain_channel: {:?}", drain_channel(rx));
+    sender.join().unwrap();
+
+    let screen = Screen::square(10);
+    for coord in ["3,4", "12,4", "three,four"] {
+        println!("place_on_screen({coord:?}): {:?}", place_on_screen(&screen, coord));
+    }
+
+    let mut heap = std::collections::BinaryHeap::new();
+    println!("top_priority: {}", top_priority(&heap));
+    heap.extend([5, 20]);
+    println!("top_priority: {}", top_priority(&heap));
+    heap.push(150);
+    println!("top_priority: {}", top_priority(&heap));
+
+    for s in ["10KB", "2MB", "500B", "5GB"] {
+        println!("parse_bytes({s:?}): {:?}", parse_bytes(s));
+    }
+
+    for align in [Align::Left, Align::Right, Align::Center] {
+        println!("pad: [{}]", pad("abc", 7, align));
+    }
+
+    let commands = ["double", "halve", "spin"];
+    for cmd in commands {
+        let shape = transform_shape(Shape::Triangle(Triangle { base: 3, height: 4 }), cmd);
+        println!("transform_shape({cmd:?}): {:?}", bounding_size(&shape));
+    }
+
+    for (level, hardcore) in [(2, false), (5, false), (9, false), (0, false), (2, true)] {
+        println!("difficulty({level}, {hardcore}): {}", difficulty(level, hardcore));
+    }
+
+    let entries = [
+        ConfigEntry::KeyValue("verbose".into(), "yes".into()),
+        ConfigEntry::KeyValue("name".into(), "server".into()),
+        ConfigEntry::Comment("# defaults".into()),
+    ];
+    for entry in &entries {
+        match (entry, config_bool(entry)) {
+            (_, Some((key, value))) => println!("config_bool: {key} = {value}"),
+            (ConfigEntry::KeyValue(key, value), None) => {
+                println!("config_bool: {key} = {value} is not a bool")
+            }
+            (ConfigEntry::Comment(text), None) => println!("config_bool: skipped comment {text}"),
+        }
+    }
+
+    use std::ops::Bound;
+    for bound in [Bound::Included(5), Bound::Excluded(5), Bound::Unbounded] {
+        println!("describe_bound: {}", describe_bound(bound));
+    }
+
+    let screen = Screen::square(10);
+    let shapes = [
+        Shape::Triangle(Triangle { base: 4, height: 6 }),
+        Shape::Rectangle { width: 10, height: 3 },
+        Shape::Circle { radius: 8 },
+    ];
+    for shape in &shapes {
+        println!("render_plan: {}", render_plan(shape, &screen));
+    }
+}
+
+#[derive(Debug)]
+enum TlvRecord {
+    Integer { tag: u8, value: i64 },
+    Text { tag: u8, value: String },
+    Bytes { tag: u8, data: Vec<u8> },
+    Unknown { tag: u8, length: u16, data: Vec<u8> },
+}
+
+#[derive(Debug)]
+enum TlvError {
+    Truncated,
+    InvalidInteger,
+    InvalidText,
+}
+
+//Slice patterns make parsing a type-length-value buffer fairly readable. Each record is a one byte
+// tag, a two byte big endian length and then `length` bytes of value. The `rest @ ..` binds
+// everything after the header so that the loop can move on to the next record.
+fn parse_tlv(mut buf: &[u8]) -> Result<Vec<TlvRecord>, TlvError> {
+    let mut records = Vec::new();
+
+    loop {
+        match buf {
+            [] => break,
+            [tag, len_hi, len_lo, rest @ ..] => {
+                let len = ((*len_hi as usize) << 8) | *len_lo as usize;
+                if rest.len() < len {
+                    return Err(TlvError::Truncated);
+                }
+
+                let record = match (tag, &rest[..len]) {
+                    (0x01, value_bytes) => {
+                        let bytes: [u8; 8] = value_bytes
+                            .try_into()
+                            .map_err(|_| TlvError::InvalidInteger)?;
+                        TlvRecord::Integer { tag: *tag, value: i64::from_be_bytes(bytes) }
+                    }
+                    (0x02, text_bytes) => {
+                        let value = std::str::from_utf8(text_bytes)
+                            .map_err(|_| TlvError::InvalidText)?;
+                        TlvRecord::Text { tag: *tag, value: value.to_string() }
+                    }
+                    (0x03, data) => TlvRecord::Bytes { tag: *tag, data: data.to_vec() },
+                    (_, data) => TlvRecord::Unknown {
+                        tag: *tag,
+                        length: len as u16,
+                        data: data.to_vec(),
+                    },
+                };
+
+                records.push(record);
+                buf = &rest[len..];
+            }
+            _ => return Err(TlvError::Truncated),
+        }
+    }
+
+    Ok(records)
+}
+
+//Slice patterns can match on the number of arguments a command was given as well as the literal
+// command name. Note that the order of the arms matters here, `[cmd, ..]` would match everything
+// the arms above it do.
+fn run_command(parts: &[&str]) -> Result<String, String> {
+    match parts {
+        ["help"] => Ok("help text".into()),
+        ["add", a, b] => {
+            let a: i64 = a.parse().map_err(|_| format!("invalid number {a}"))?;
+            let b: i64 = b.parse().map_err(|_| format!("invalid number {b}"))?;
+            Ok(a.checked_add(b).ok_or_else(|| "overflow".to_string())?.to_string())
+        }
+        ["echo", rest @ ..] => Ok(rest.join(" ")),
+        [cmd, ..] => Err(format!("unknown {cmd}")),
+        [] => Err("empty".into()),
+    }
+}
+
+enum WsFrame {
+    Text(String),
+    Binary(Vec<u8>),
+    Ping(Vec<u8>),
+    Pong(Vec<u8>),
+    Close(u16, String),
+}
+
+#[derive(Debug)]
+enum WsError {
+    TooShort,
+    UnknownOpcode(u8),
+    InvalidText,
+    Unsupported,
+}
+
+impl From<std::str::Utf8Error> for WsError {
+    fn from(_: std::str::Utf8Error) -> Self {
+        WsError::InvalidText
+    }
+}
+
+//This only handles small unmasked frames where the payload length fits in the second byte. The
+// first two bytes are matched as a slice and then the opcode and payload are matched together as a
+// tuple. The close frame even uses a nested slice pattern to pull out the status code. An empty
+// close frame is allowed and has no status code, which RFC 6455 reports as 1005. A set mask bit or
+// one of the extended lengths 126 and 127 is rejected up front instead of being misread.
+fn parse_frame(buf: &[u8]) -> Result<(WsFrame, &[u8]), WsError> {
+    match buf {
+        [_, b1, ..] if b1 & 0x80 != 0 => Err(WsError::Unsupported),
+        [_, 126 | 127, ..] => Err(WsError::Unsupported),
+        [b0, b1, payload @ ..] => {
+            let opcode = b0 & 0x0F;
+            let payload_len = (b1 & 0x7F) as usize;
+            if payload.len() < payload_len {
+                return Err(WsError::TooShort);
+            }
+            let rest = &payload[payload_len..];
+
+            let frame = match (opcode, &payload[..payload_len]) {
+                (0x1, data) => WsFrame::Text(std::str::from_utf8(data)?.to_string()),
+                (0x2, data) => WsFrame::Binary(data.to_vec()),
+                (0x8, [hi, lo, reason @ ..]) => WsFrame::Close(
+                    ((*hi as u16) << 8) | *lo as u16,
+                    std::str::from_utf8(reason)?.to_string(),
+                ),
+                (0x8, []) => WsFrame::Close(1005, String::new()),
+                (0x8, _) => return Err(WsError::TooShort),
+                (0x9, data) => WsFrame::Ping(data.to_vec()),
+                (0xA, data) => WsFrame::Pong(data.to_vec()),
+                _ => return Err(WsError::UnknownOpcode(opcode)),
+            };
+
+            Ok((frame, rest))
+        }
+        _ => Err(WsError::TooShort),
+    }
+}
+
+//The ranges here cover 0 through 255 so the match is exhaustive without a wildcard. If one of the
+// ranges had a gap the compiler would point out exactly which values were missing.
+fn ip_class(first_octet: u8) -> char {
+    match first_octet {
+        0..=127 => 'A',
+        128..=191 => 'B',
+        192..=223 => 'C',
+        224..=239 => 'D',
+        240..=255 => 'E',
+    }
+}
+
+//A `match` is an expression so it can be the entire body of a closure.
+fn label_signs(nums: &[i32]) -> Vec<&'static str> {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    nums.iter()
+        .map(|&n| match n.cmp(&0) {
+            Less => "neg",
+            Equal => "zero",
+            Greater => "pos",
+        })
+        .collect()
+}
+
+struct Config {
+    host: String,
+    port: u16,
+    workers: usize,
+}
+
+#[derive(Default)]
+struct ConfigBuilder {
+    host: Option<String>,
+    port: Option<u16>,
+    workers: Option<usize>,
+}
+
+impl ConfigBuilder {
+    //`let...else` binds the variable when the pattern matches and otherwise runs the `else` block,
+    // which must diverge (return, break, panic etc...). This keeps the happy path at the top level
+    // instead of nesting every following validation inside of an `if let`.
+    fn build(self) -> Result<Config, Vec<&'static str>> {
+        let mut errors = Vec::new();
+
+        if let Some(0) = self.workers {
+            errors.push("workers must be greater than zero");
+        }
+
+        let Some(host) = self.host else {
+            errors.push("host required");
+            return Err(errors);
+        };
+
+        let host = host.trim();
+        if host.is_empty() {
+            errors.push("host empty");
+        }
+
+        if !errors.is_empty() {
+            return Err(errors);
+        }
+
+        Ok(Config {
+            host: host.to_string(),
+            port: self.port.unwrap_or(8080),
+            workers: self.workers.unwrap_or(1),
+        })
+    }
+}
+
+enum QueryOp<T> {
+    Eq(T),
+    NotEq(T),
+    Lt(T),
+    Gt(T),
+    In(Vec<T>),
+    And(Box<QueryOp<T>>, Box<QueryOp<T>>),
+    Or(Box<QueryOp<T>>, Box<QueryOp<T>>),
+    Not(Box<QueryOp<T>>),
+}
+
+//Each operator maps to a single arm. The `And`, `Or` and `Not` arms call back into `matches` which
+// is what makes the filters composable.
+fn matches<T: PartialOrd>(val: &T, op: &QueryOp<T>) -> bool {
+    match op {
+        QueryOp::Eq(v) => val == v,
+        QueryOp::NotEq(v) => val != v,
+        QueryOp::Lt(v) => val < v,
+        QueryOp::Gt(v) => val > v,
+        QueryOp::In(vs) => vs.contains(val),
+        QueryOp::And(a, b) => matches(val, a) && matches(val, b),
+        QueryOp::Or(a, b) => matches(val, a) || matches(val, b),
+        QueryOp::Not(inner) => !matches(val, inner),
+    }
+}
+
+//A truth table can be written almost exactly as it would be on paper by matching a tuple of bools.
+// Clippy would prefer `matches!` here, but the point is to show the or-pattern in a full match.
+#[allow(clippy::match_like_matches_macro)]
+fn xor_table(a: bool, b: bool) -> bool {
+    match (a, b) {
+        (true, false) | (false, true) => true,
+        _ => false,
+    }
+}
+
+//Two right triangles are similar when their base to height ratios are the same. Cross multiplying
+// the ratios means no floating point division is needed, so there are no precision issues. Clippy
+// points out that matching `true` to `true` is the same as returning the bool, but the arms spell
+// out both outcomes of the comparison. The sides are widened to `i128` first so that multiplying
+// two large sides can not overflow.
+#[allow(clippy::needless_match)]
+fn similar(a: &Triangle, b: &Triangle) -> bool {
+    let (ab, ah, bb, bh) = (a.base as i128, a.height as i128, b.base as i128, b.height as i128);
+    match ab * bh == bb * ah {
+        true => true,
+        false => false,
+    }
+}
+
+//Match arms are checked from top to bottom and the first one that matches wins. Here `5..=10` is
+// covered by the `0..=10` arm above it, so those values will always be "low" and never
+// "medium-or-low". Only 11 to 15 ever reach the second arm. The compiler itself does not warn about
+// this because the second arm is still reachable for some values. Clippy does though, it reports
+// "some ranges overlap" with `match_overlapping_arm`, which is allowed here on purpose.
+#[allow(clippy::match_overlapping_arm)]
+fn demonstrate_arm_ordering(x: i32) -> &'static str {
+    match x {
+        0..=10 => "low",
+        5..=15 => "medium-or-low",
+        11..=20 => "medium",
+        _ => "high",
+    }
+}
+
+//The fix is to make the ranges not overlap so that the order of the arms no longer matters.
+fn correct_ordering(x: i32) -> &'static str {
+    match x {
+        0..=4 => "low",
+        5..=10 => "medium-low",
+        11..=20 => "medium",
+        _ => "high",
+    }
+}
+
+#[derive(Clone, Copy)]
+enum CsvState {
+    Field,
+    Quoted,
+    Escaped,
+}
+
+//A state machine can be written as a single match over a tuple of the current state and the next
+// input. `Escaped` means a quote was seen inside of a quoted field, it is either the start of a
+// doubled `""` or the end of the quoted section.
+fn parse_csv_line(line: &str) -> Vec<String> {
+    let mut fields = Vec::new();
+    let mut field = String::new();
+    let mut state = CsvState::Field;
+
+    for ch in line.chars() {
+        state = match (state, ch) {
+            (CsvState::Field, ',') | (CsvState::Escaped, ',') => {
+                fields.push(std::mem::take(&mut field));
+                CsvState::Field
+            }
+            (CsvState::Field, '"') => CsvState::Quoted,
+            (CsvState::Field, c) => {
+                field.push(c);
+                CsvState::Field
+            }
+            (CsvState::Quoted, '"') => CsvState::Escaped,
+            (CsvState::Quoted, c) => {
+                field.push(c);
+                CsvState::Quoted
+            }
+            (CsvState::Escaped, '"') => {
+                field.push('"');
+                CsvState::Quoted
+            }
+            (CsvState::Escaped, c) => {
+                field.push(c);
+                CsvState::Field
+            }
+        };
+    }
+
+    fields.push(field);
+    fields
+}
+
+//Because `opt` is matched by value the `String` inside of it is moved into `s`. That means `s` can
+// be returned directly without a clone.
+fn extract_or_default(opt: Option<String>, default: &str) -> String {
+    match opt {
+        Some(s) => s,
+        None => default.to_string(),
+    }
+}
+
+#[derive(Debug)]
+enum AppError {
+    NotFound,
+    Invalid(String),
+    Timeout,
+}
+
+type AppResult<T> = Result<T, AppError>;
+
+//A type alias is only another name for the type, so the `Result` patterns work through it exactly
+// the same. The error variants can be matched right inside of the `Err` pattern.
+fn handle<T>(r: AppResult<T>) -> Option<T> {
+    match r {
+        Ok(v) => Some(v),
+        Err(AppError::NotFound) => None,
+        Err(_) => None,
+    }
+}
+
+fn word_counts(text: &str) -> std::collections::HashMap<&str, usize> {
+    let mut counts = std::collections::HashMap::new();
+    for word in text.split_whitespace() {
+        *counts.entry(word).or_insert(0) += 1;
+    }
+    counts
+}
+
+//`or_insert` is really just a match on the `Entry` enum. Writing it out shows both cases, the key
+// is either already in the map or it is not.
+fn word_counts_entry_match(text: &str) -> std::collections::HashMap<&str, usize> {
+    use std::collections::hash_map::Entry;
+
+    let mut counts = std::collections::HashMap::new();
+    for word in text.split_whitespace() {
+        match counts.entry(word) {
+            Entry::Occupied(mut entry) => *entry.get_mut() += 1,
+            Entry::Vacant(entry) => {
+                entry.insert(1);
+            }
+        }
+    }
+    counts
+}
+
+//This takes a `&Vec` instead of a slice because a slice has no capacity.
+fn vec_state<T>(v: &Vec<T>) -> &'static str {
+    match (v.is_empty(), v.len() == v.capacity()) {
+        (true, _) => "empty",
+        (false, true) => "full",
+        (false, false) => "has room",
+    }
+}
+
+fn get_mode() -> String {
+    get_mode_from(std::env::var("APP_MODE"))
+}
+
+//Taking the `Result` as a parameter means the match can be checked without setting any real
+// environment variables.
+fn get_mode_from(var: Result<String, std::env::VarError>) -> String {
+    match var {
+        Ok(m) => m,
+        Err(_) => "default".into(),
+    }
+}
+
+//The literal `0` arm is checked first and any other value falls through to the `b` binding. Adding
+// zero would not change the sum anyway, but it shows a literal arm next to a catch all binding.
+fn simple_checksum(bytes: &[u8]) -> u8 {
+    bytes.iter().fold(0u8, |acc, &b| match b {
+        0 => acc,
+        b => acc.wrapping_add(b),
+    })
+}
+
+//The guard on the first arm has to come before the ranges. `0.0.log10()` is negative infinity and
+// casting that to an `i32` saturates to `i32::MIN`, so without the guard zero would be a
+// "fraction".
+fn magnitude_bucket(x: f64) -> &'static str {
+    match x.abs().log10().floor() as i32 {
+        _ if x == 0.0 => "zero",
+        i32::MIN..=-1 => "fraction",
+        0..=2 => "small",
+        3..=5 => "medium",
+        _ => "large",
+    }
+}
+
+//`match`, including guards, can be used inside of a `const fn`. The restriction is that everything
+// in the arms has to be something that can be evaluated at compile time as well, so things like
+// allocating a `String` or calling a normal function are not allowed.
+const fn classify_const(n: i32) -> i32 {
+    match n {
+        0 => 0,
+        _ if n > 0 => 1,
+        _ => -1,
+    }
+}
+
+const CLASSIFIED: [i32; 3] = [classify_const(-5), classify_const(0), classify_const(5)];
+
+enum ScreenCmd {
+    Resize(isize),
+    MoveBy(isize, isize),
+    Reset,
+}
+
+//Each command variant carries exactly the data it needs, so the match arms can use it directly to
+// update the screen in place.
+fn apply_cmd(s: &mut Screen, cmd: ScreenCmd) {
+    match cmd {
+        ScreenCmd::Resize(size) => s.size = size,
+        ScreenCmd::MoveBy(dx, dy) => {
+            s.x += dx;
+            s.y += dy;
+        }
+        ScreenCmd::Reset => {
+            s.x = 0;
+            s.y = 0;
+        }
+    }
+}
+
+//The `_` in each position means that arm does not care about that classification. A char can only
+// be one of these anyway, but the arm order would decide if two of them were ever true at once.
+fn char_category(c: char) -> &'static str {
+    match (c.is_alphabetic(), c.is_numeric(), c.is_whitespace()) {
+        (true, _, _) => "alpha",
+        (_, true, _) => "num",
+        (_, _, true) => "space",
+        _ => "symbol",
+    }
+}
+
+enum Nested {
+    Leaf(i32),
+    List(Vec<Nested>),
+}
+
+//The `List` arm calls `flatten` on each of its items, so the match recurses until it reaches the
+// leaves no matter how deep the nesting goes.
+fn flatten(n: &Nested) -> Vec<i32> {
+    match n {
+        Nested::Leaf(v) => vec![*v],
+        Nested::List(items) => items.iter().flat_map(flatten).collect(),
+    }
+}
+
+fn length_of(s: &str) -> usize {
+    match s.is_empty() {
+        true => 0,
+        false => s.len(),
+    }
+}
+
+//The parenthesis are needed to bind `c` to the whole or-pattern. Without them `c @ 200..=299` would
+// be one pattern and `300..=399` another one without the binding, which does not compile. Clippy
+// would rather this be `200..=399`, but keeping success and redirect separate reads closer to HTTP.
+#[allow(clippy::manual_range_patterns)]
+fn http_category(code: u16) -> String {
+    match code {
+        c @ (200..=299 | 300..=399) => format!("ok-ish {c}"),
+        c @ 400..=599 => format!("error {c}"),
+        c => format!("unknown {c}"),
+    }
+}
+
+//A big table of literals is no problem for a match. The compiler can turn this into a jump table so
+// it does not have to check each arm one at a time.
+fn to_morse(c: char) -> Option<&'static str> {
+    let code = match c {
+        'a' => ".-",
+        'b' => "-...",
+        'c' => "-.-.",
+        'd' => "-..",
+        'e' => ".",
+        'f' => "..-.",
+        'g' => "--.",
+        'h' => "....",
+        'i' => "..",
+        'j' => ".---",
+        'k' => "-.-",
+        'l' => ".-..",
+        'm' => "--",
+        'n' => "-.",
+        'o' => "---",
+        'p' => ".--.",
+        'q' => "--.-",
+        'r' => ".-.",
+        's' => "...",
+        't' => "-",
+        'u' => "..-",
+        'v' => "...-",
+        'w' => ".--",
+        'x' => "-..-",
+        'y' => "-.--",
+        'z' => "--..",
+        '0' => "-----",
+        '1' => ".----",
+        '2' => "..---",
+        '3' => "...--",
+        '4' => "....-",
+        '5' => ".....",
+        '6' => "-....",
+        '7' => "--...",
+        '8' => "---..",
+        '9' => "----.",
+        _ => return None,
+    };
+    Some(code)
+}
+
+//`middle @ ..` binds everything between the first and last element as a new slice, so each call
+// peels one char off of both ends. The guard makes the arm fall through to `_` when they differ.
+fn is_palindrome(s: &[char]) -> bool {
+    match s {
+        [] | [_] => true,
+        [first, middle @ .., last] if first == last => is_palindrome(middle),
+        _ => false,
+    }
+}
+
+enum SortOrder {
+    Asc,
+    Desc,
+}
+
+//The match picks which way the comparison goes, everything else about the sort stays the same.
+// Closures that do not capture anything can be coerced into a plain `fn` pointer, which is what
+// lets both arms have the same type.
+fn sorted<T: Ord + Clone>(items: &[T], order: SortOrder) -> Vec<T> {
+    let compare: fn(&T, &T) -> std::cmp::Ordering = match order {
+        SortOrder::Asc => |a, b| a.cmp(b),
+        SortOrder::Desc => |a, b| b.cmp(a),
+    };
+
+    let mut items = items.to_vec();
+    items.sort_by(compare);
+    items
+}
+
+//The tuple from `enumerate` is destructured in the `for` pattern, and `&n` copies the value out of
+// the reference at the same time. Returning from inside of a match arm returns from the function.
+fn find_first_negative_index(nums: &[i32]) -> Option<usize> {
+    for (i, &n) in nums.iter().enumerate() {
+        match n {
+            x if x < 0 => return Some(i),
+            _ => {}
+        }
+    }
+    None
+}
+
+//The state is the remainder of the number read so far divided by three. Reading another bit doubles
+// the number and adds the bit, so each `(state, bit)` pair maps to `(state * 2 + bit) % 3`. Writing
+// out every transition as its own arm makes the table of the DFA easy to check. Anything other than
+// a `0` or `1` is rejected.
+fn divisible_by_three(bits: &str) -> bool {
+    let mut state = 0;
+
+    for bit in bits.chars() {
+        state = match (state, bit) {
+            (0, '0') => 0,
+            (0, '1') => 1,
+            (1, '0') => 2,
+            (1, '1') => 0,
+            (2, '0') => 1,
+            (2, '1') => 2,
+            _ => return false,
+        };
+    }
+
+    state == 0
+}
+
+//Matching on a `&mut Screen` makes `x` and `y` into `&mut isize` bindings, so the fields can be
+// changed through them. The guard only sees them as shared references while it is checked though.
+fn normalize_screen(s: &mut Screen) {
+    match s {
+        Screen { x, y, .. } if *x < 0 || *y < 0 => {
+            *x = (*x).max(0);
+            *y = (*y).max(0);
+        }
+        _ => {}
+    }
+}
+
+//String literals can be used as patterns for a `&str`, and a guard handles what a literal can not.
+fn text_state(s: &str) -> &'static str {
+    match s {
+        "" => "empty",
+        t if t.trim().is_empty() => "blank",
+        _ => "content",
+    }
+}
+
+//Each range of the base64 alphabet is offset from the start of its range, so the arm needs the
+// value itself as well as the range it falls in. Only 0 to 63 are valid sextets.
+fn encode_sextet(value: u8) -> char {
+    match value {
+        0..=25 => (b'A' + value) as char,
+        26..=51 => (b'a' + value - 26) as char,
+        52..=61 => (b'0' + value - 52) as char,
+        62 => '+',
+        63 => '/',
+        _ => '=',
+    }
+}
+
+//A guard can use anything that is in scope, not only the value being matched.
+fn is_allowed(id: u32, allowlist: &[u32]) -> &'static str {
+    match id {
+        x if allowlist.contains(&x) => "allowed",
+        _ => "denied",
+    }
+}
+
+//The last char is the unit and everything before it is the number. Once the number is parsed the
+// unit can be matched to decide how many seconds each one is worth.
+fn parse_duration(s: &str) -> Option<u64> {
+    let mut chars = s.chars();
+    let suffix = chars.next_back()?;
+    let secs: u64 = chars.as_str().parse().ok()?;
+
+    match suffix {
+        's' => Some(secs),
+        'm' => secs.checked_mul(60),
+        'h' => secs.checked_mul(3600),
+        _ => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum State {
+    Idle,
+    Running,
+    Paused,
+    Stopped,
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Event {
+    Start,
+    Pause,
+    Resume,
+    Stop,
+}
+
+//Every valid transition gets its own arm. Anything that is left over ends up in the last arm, which
+// keeps the current state and reports that nothing happened.
+fn transition(current: State, event: Event) -> (State, bool) {
+    match (current, event) {
+        (State::Idle, Event::Start) => (State::Running, true),
+        (State::Running, Event::Pause) => (State::Paused, true),
+        (State::Paused, Event::Resume) => (State::Running, true),
+        (State::Running | State::Paused, Event::Stop) => (State::Stopped, true),
+        (state, _) => (state, false),
+    }
+}
+
+//`@` can bind the value being matched at the top level too. The last arm does not need it because a
+// plain binding already matches and binds anything.
+fn describe_range_value(n: i32) -> String {
+    match n {
+        whole @ 1..=10 => format!("{whole} is single digit-ish"),
+        whole @ 11..=100 => format!("{whole} is double digit-ish"),
+        whole => format!("{whole} is big"),
+    }
+}
+
+//Ties are broken alphabetically so that the output is always in the same order, a `HashMap` does
+// not iterate in any particular order.
+fn top_words(text: &str, n: usize) -> Vec<(String, usize)> {
+    let mut words: Vec<(String, usize)> = word_counts_entry_match(text)
+        .into_iter()
+        .map(|(word, count)| (word.to_string(), count))
+        .collect();
+
+    words.sort_by(|(word_a, count_a), (word_b, count_b)| {
+        count_b.cmp(count_a).then_with(|| word_a.cmp(word_b))
+    });
+    words.truncate(n);
+    words
+}
+
+enum Either {
+    Left(String),
+    Right(Vec<i32>),
+}
+
+//Matching on a reference means `s` is a `&String` and `v` is a `&Vec<i32>`. Each arm only borrows
+// the data that its variant holds.
+fn describe_either(e: &Either) -> String {
+    match e {
+        Either::Left(s) => format!("text len {}", s.len()),
+        Either::Right(v) => format!("vec len {}", v.len()),
+    }
+}
+
+#[derive(Debug)]
+enum PointResult {
+    Inside,
+    OnEdge,
+    Outside,
+}
+
+impl Screen {
+    //Most of the examples only care about the size, so this puts the screen at the origin with a
+    // unit triangle on it. Struct update syntax can fill in the rest when `x` or `y` matter.
+    fn square(size: isize) -> Screen {
+        Screen { size, x: 0, y: 0, t: Triangle { base: 1, height: 1 } }
+    }
+
+    //The screen is a square that starts at `x` and `y` and is `size` long on each side. The edge
+    // check only matters once the point is known to be within both ranges.
+    fn locate(&self, px: isize, py: isize) -> PointResult {
+        let right = self.x + self.size;
+        let bottom = self.y + self.size;
+
+        match (
+            (self.x..=right).contains(&px),
+            (self.y..=bottom).contains(&py),
+            px == self.x || px == right || py == self.y || py == bottom,
+        ) {
+            (true, true, true) => PointResult::OnEdge,
+            (true, true, false) => PointResult::Inside,
+            _ => PointResult::Outside,
+        }
+    }
+}
+
+//Both of these add the values together when both are `Some`, and otherwise keep whichever one is
+// `Some`. A sum that overflows gives `None`. The nested version needs a second match on `b` inside
+// of the match on `a`.
+
+fn combine_options_nested(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+    match a {
+        Some(x) => match b {
+            Some(y) => x.checked_add(y),
+            None => Some(x),
+        },
+        None => b,
+    }
+}
+
+//Matching on a tuple of both values flattens it out into one arm per combination.
+fn combine_options(a: Option<i32>, b: Option<i32>) -> Option<i32> {
+    match (a, b) {
+        (Some(x), Some(y)) => x.checked_add(y),
+        (Some(x), None) => Some(x),
+        (None, Some(y)) => Some(y),
+        (None, None) => None,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum CmpOp {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+}
+
+fn compare(op: CmpOp, a: i32, b: i32) -> bool {
+    match op {
+        CmpOp::Eq => a == b,
+        CmpOp::Ne => a != b,
+        CmpOp::Lt => a < b,
+        CmpOp::Le => a <= b,
+        CmpOp::Gt => a > b,
+        CmpOp::Ge => a >= b,
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+enum Cell {
+    Alive,
+    Dead,
+}
+
+//These are all of the rules for Conway's game of life. A live cell with two or three neighbors
+// survives, a dead cell with exactly three comes to life and every other cell is dead.
+fn next_cell(current: Cell, live_neighbors: u8) -> Cell {
+    match (current, live_neighbors) {
+        (Cell::Alive, 2 | 3) => Cell::Alive,
+        (Cell::Dead, 3) => Cell::Alive,
+        _ => Cell::Dead,
+    }
+}
+
+//Draws a right triangle with the right angle in the bottom left. Each row is as wide as the base
+// scaled by how far down the row is, but it never goes below a single `#` so the top is visible.
+// A base over 100 is rejected too, otherwise the rows could be huge or the scaling could overflow.
+fn ascii_art(t: &Triangle) -> String {
+    let height = match t.height {
+        h if h > 100 || t.base > 100 => return "too large".into(),
+        h if h <= 0 || t.base <= 0 => return String::new(),
+        h => h,
+    };
+
+    let mut art = String::new();
+    for row in 1..=height {
+        let width = match t.base * row / height {
+            0 => 1,
+            w => w,
+        };
+        art.push_str(&"#".repeat(width as usize));
+        art.push('\n');
+    }
+    art
+}
+
+//Slice patterns do not work on a `Vec` directly, but `as_slice` gives a slice that they do work on.
+fn describe_vec(v: &Vec<i32>) -> &'static str {
+    match v.as_slice() {
+        [] => "empty",
+        [_] => "single",
+        [_, _] => "pair",
+        _ => "many",
+    }
+}
+
+enum Bool {
+    Lit(bool),
+    And(Box<Bool>, Box<Bool>),
+    Or(Box<Bool>, Box<Bool>),
+    Not(Box<Bool>),
+}
+
+fn eval_bool(b: &Bool) -> bool {
+    match b {
+        Bool::Lit(value) => *value,
+        Bool::And(left, right) => eval_bool(left) && eval_bool(right),
+        Bool::Or(left, right) => eval_bool(left) || eval_bool(right),
+        Bool::Not(inner) => !eval_bool(inner),
+    }
+}
+
+//`split_first` and `split_last` both return an `Option` of a tuple, so the whole thing can be
+// matched in one nested pattern. `&f` and `&l` copy the values out of the references.
+fn frame(nums: &[i32]) -> Option<(i32, i32)> {
+    match (nums.split_first(), nums.split_last()) {
+        (Some((&f, _)), Some((&l, _))) => Some((f, l)),
+        _ => None,
+    }
+}
+
+//Any string that parses as an `i64` would also parse as an `f64`, so the `int` arm has to be first.
+fn number_kind(s: &str) -> &'static str {
+    match (s.parse::<i64>(), s.parse::<f64>()) {
+        (Ok(_), _) => "int",
+        (Err(_), Ok(_)) => "float",
+        _ => "invalid",
+    }
+}
+
+//Each bit index is paired with that bit masked out of `bits`. A masked value of `0` means the flag
+// is not set, so that arm comes first and the rest only have to look at the index.
+fn decode_flags(bits: u8) -> Vec<&'static str> {
+    (0..3)
+        .filter_map(|index| match (index, bits & (1 << index)) {
+            (_, 0) => None,
+            (0, _) => Some("read"),
+            (1, _) => Some("write"),
+            _ => Some("execute"),
+        })
+        .collect()
+}
+
+fn suggest(distance: usize) -> &'static str {
+    match distance {
+        0 => "exact",
+        1 => "close",
+        2..=3 => "maybe",
+        _ => "no match",
+    }
+}
+
+//This is the Levenshtein distance, the number of single char insertions, deletions or substitutions
+// needed to turn `a` into `b`. Only the previous row of the table is kept around.
+fn edit_distance(a: &str, b: &str) -> usize {
+    let b: Vec<char> = b.chars().collect();
+    let mut previous: Vec<usize> = (0..=b.len()).collect();
+
+    for (i, ca) in a.chars().enumerate() {
+        let mut current = vec![i + 1];
+        for (j, cb) in b.iter().enumerate() {
+            let substitution = previous[j] + usize::from(ca != *cb);
+            let insertion = current[j] + 1;
+            let deletion = previous[j + 1] + 1;
+            current.push(substitution.min(insertion).min(deletion));
+        }
+        previous = current;
+    }
+
+    previous[b.len()]
+}
+
+#[derive(Debug)]
+enum Color {
+    Red,
+    Green,
+    Blue,
+}
+
+trait Parse: Sized {
+    fn parse(s: &str) -> Option<Self>;
+}
+
+//Nothing is special about matching inside of a trait implementation, `Self` can even be used in
+// place of the type name inside of the arms.
+impl Parse for Color {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "red" => Some(Self::Red),
+            "green" => Some(Self::Green),
+            "blue" => Some(Self::Blue),
+            _ => None,
+        }
+    }
+}
+
+//`peek` returns a reference to the next item without moving past it. Since the iterator is already
+// over `&i32` the peeked value is a `&&i32`, which is why the pattern needs `&&n`. A value is only
+// kept once the next one is different, so the last item of each run is the one that is pushed.
+fn dedup_adjacent(items: &[i32]) -> Vec<i32> {
+    let mut deduped = Vec::new();
+    let mut iter = items.iter().peekable();
+
+    while let Some(&current) = iter.next() {
+        match (current, iter.peek()) {
+            (c, Some(&&n)) if c == n => continue,
+            _ => deduped.push(current),
+        }
+    }
+
+    deduped
+}
+
+struct Version {
+    major: u32,
+    minor: u32,
+    patch: u32,
+}
+
+//The first part that is not `Equal` decides the ordering. The arms go from the most specific to
+// the least, the patch only matters when major and minor are both equal.
+fn compare_versions(a: &Version, b: &Version) -> std::cmp::Ordering {
+    use std::cmp::Ordering::Equal;
+
+    match (a.major.cmp(&b.major), a.minor.cmp(&b.minor), a.patch.cmp(&b.patch)) {
+        (Equal, Equal, p) => p,
+        (Equal, m, _) => m,
+        (maj, _, _) => maj,
+    }
+}
+
+//This is what `while let Some(v) = iter.next()` is doing behind the scenes. `while let` is just a
+// shorter way to write a `loop` with a `match` that breaks on the arm that does not match. Clippy
+// suggests exactly that rewrite, but here the long form is the point.
+#[allow(clippy::while_let_loop)]
+fn manual_sum(mut iter: impl Iterator<Item = i32>) -> i32 {
+    let mut total = 0;
+    loop {
+        match iter.next() {
+            Some(v) => total += v,
+            None => break,
+        }
+    }
+    total
+}
+
+//The first 10000 is not taxed, the next 30000 is taxed at 20% and everything above 40000 is taxed
+// at 40%. Each arm adds the tax for its own band on top of the full tax from the bands below it.
+// The math is done in a `u64` so that multiplying a large income by the rate can not overflow.
+fn tax_owed(income: u32) -> u32 {
+    let tax = match income as u64 {
+        0..=10000 => 0,
+        i @ 10001..=40000 => (i - 10000) * 20 / 100,
+        i => 30000 * 20 / 100 + (i - 40000) * 40 / 100,
+    };
+    tax as u32
+}
+
+fn minmax(nums: &[i32]) -> Option<(i32, i32)> {
+    let (&first, rest) = nums.split_first()?;
+    let mut lo = first;
+    let mut hi = first;
+    for &n in rest {
+        lo = lo.min(n);
+        hi = hi.max(n);
+    }
+    Some((lo, hi))
+}
+
+#[derive(Debug)]
+enum Dir {
+    N,
+    S,
+    E,
+    W,
+}
+
+enum Key {
+    Char(char),
+    Enter,
+    Backspace,
+    Arrow(Dir),
+}
+
+//The arrow keys do not change the text, so the direction inside of them is ignored with `_`.
+fn handle_key(buffer: &mut String, key: Key) {
+    match key {
+        Key::Char(c) => buffer.push(c),
+        Key::Backspace => {
+            buffer.pop();
+        }
+        Key::Enter => buffer.push('\n'),
+        Key::Arrow(_) => {}
+    }
+}
+
+//The message inside of `Invalid` does not change the status code so it is ignored with `_`.
+fn status_code(e: &AppError) -> u16 {
+    match e {
+        AppError::NotFound => 404,
+        AppError::Invalid(_) => 422,
+        AppError::Timeout => 504,
+    }
+}
+
+//The guard is checked before the arm runs, so `v[i]` is only ever reached when it can not panic.
+fn safe_index(v: &[i32], i: usize) -> Option<i32> {
+    match i {
+        _ if i < v.len() => Some(v[i]),
+        _ => None,
+    }
+}
+
+//This evaluates the tokens in reverse polish notation, the operator comes after both of the values
+// it uses. Popping two values at once and matching them as a tuple checks that there were enough
+// values on the stack. At the end the stack has to have exactly one value left in it.
+fn eval_keywords(tokens: &[&str]) -> Option<bool> {
+    let mut stack = Vec::new();
+
+    for token in tokens {
+        match *token {
+            "true" => stack.push(true),
+            "false" => stack.push(false),
+            op @ ("and" | "or") => match (stack.pop(), stack.pop()) {
+                (Some(b), Some(a)) => stack.push(if op == "and" { a && b } else { a || b }),
+                _ => return None,
+            },
+            _ => return None,
+        }
+    }
+
+    match stack.as_slice() {
+        [result] => Some(*result),
+        _ => None,
+    }
+}
+
+//`binary_search` returns `Ok` with the index when the value is found and `Err` with the index it
+// would be inserted at when it is not. Either way that index is the answer here.
+fn insert_position(sorted: &[i32], target: i32) -> usize {
+    match sorted.binary_search(&target) {
+        Ok(i) => i,
+        Err(i) => i,
+    }
+}
+
+enum Shape {
+    Triangle(Triangle),
+    Rectangle { width: isize, height: isize },
+    Circle { radius: isize },
+}
+
+//Only pairs of the same variant get their own arm, any mix of two different variants falls through
+// to the last arm. A rectangle rotated by 90 degrees is still congruent, so both orders are
+// checked.
+fn congruent(a: &Shape, b: &Shape) -> bool {
+    match (a, b) {
+        (Shape::Triangle(a), Shape::Triangle(b)) => a.base == b.base && a.height == b.height,
+        (
+            Shape::Rectangle { width: wa, height: ha },
+            Shape::Rectangle { width: wb, height: hb },
+        ) => (wa == wb && ha == hb) || (wa == hb && ha == wb),
+        (Shape::Circle { radius: ra }, Shape::Circle { radius: rb }) => ra == rb,
+        _ => false,
+    }
+}
+
+//An array has a known length, so this one arm always matches and no `_` arm is needed.
+#[allow(clippy::match_single_binding)]
+fn ends(arr: [i32; 5]) -> (i32, i32) {
+    match arr {
+        [first, .., last] => (first, last),
+    }
+}
+
+
+//Sleeping wins over everything so it is first, then the weekend, and only then the work hours.
+// Moving the `(_, true)` arm to the top would mean no sleep on weekends.
+fn schedule(hour: u8, is_weekend: bool) -> &'static str {
+    match (hour, is_weekend) {
+        (0..=5, _) => "sleep",
+        (_, true) => "relax",
+        (9..=17, false) => "work",
+        _ => "free",
+    }
+}
+
+//Destructuring takes the fields out of `t` by value, then a new `Triangle` is built from them. The
+// pattern can not fail, so the one arm covers every `Triangle`.
+#[allow(clippy::match_single_binding)]
+fn adjusted(t: Triangle, delta: isize) -> Triangle {
+    match t {
+        Triangle { base, height } => Triangle { base: base + delta, height: height + delta },
+    }
+}
+
+fn before_delimiter(s: &str, delim: char) -> &str {
+    match s.find(delim) {
+        Some(i) => &s[..i],
+        None => s,
+    }
+}
+
+enum ConfigV1 {
+    Name(String),
+}
+
+enum ConfigV2 {
+    Name(String),
+    Age(u32),
+}
+
+//Every variant of the old version needs somewhere to go in the new one. Because the match has to
+// be exhaustive, adding a variant to `ConfigV1` without updating this would not compile.
+fn migrate(v1: ConfigV1) -> ConfigV2 {
+    match v1 {
+        ConfigV1::Name(n) => ConfigV2::Name(n),
+    }
+}
+
+fn medal(rank: u32) -> Option<&'static str> {
+    match rank {
+        1 => Some("gold"),
+        2 => Some("silver"),
+        3 => Some("bronze"),
+        _ => None,
+    }
+}
+
+fn medals(ranks: &[u32]) -> Vec<Option<&'static str>> {
+    ranks.iter().map(|&rank| medal(rank)).collect()
+}
+
+//`Ordering` is a normal enum so its variants can be combined with `|` like any other pattern.
+fn within_timeout(elapsed: std::time::Duration, limit: std::time::Duration) -> &'static str {
+    use std::cmp::Ordering::{Equal, Greater, Less};
+
+    match elapsed.cmp(&limit) {
+        Less | Equal => "ok",
+        Greater => "timeout",
+    }
+}
+
+fn hex_nibble(b: u8) -> Option<u8> {
+    match b {
+        b'0'..=b'9' => Some(b - b'0'),
+        b'a'..=b'f' => Some(b - b'a' + 10),
+        b'A'..=b'F' => Some(b - b'A' + 10),
+        _ => None,
+    }
+}
+
+//The slice pattern checks that there are exactly six digits and names each one in the same step.
+// Anything shorter or longer falls through to the `_` arm.
+fn parse_hex_color(s: &str) -> Option<(u8, u8, u8)> {
+    let digits = s.strip_prefix('#').unwrap_or(s);
+
+    match digits.as_bytes() {
+        [r1, r2, g1, g2, b1, b2] => {
+            let pair = |hi: u8, lo: u8| Some((hex_nibble(hi)? << 4) | hex_nibble(lo)?);
+            Some((pair(*r1, *r2)?, pair(*g1, *g2)?, pair(*b1, *b2)?))
+        }
+        _ => None,
+    }
+}
+
+//`break` can give a value to a `loop`, and with a label it is clear which loop the value is for.
+// That lets a match arm end the loop and produce the result at the same time. Squares that do not
+// fit in an `i32` come back from `checked_mul` as `None` and are skipped.
+fn first_even_square(nums: &[i32]) -> Option<i32> {
+    let mut iter = nums.iter();
+
+    'outer: loop {
+        let Some(&n) = iter.next() else {
+            break 'outer None;
+        };
+
+        match n.checked_mul(n) {
+            Some(square) if square % 2 == 0 => break 'outer Some(square),
+            _ => continue 'outer,
+        }
+    }
+}
+
+enum System {
+    Metric,
+    Imperial,
+}
+
+fn format_length(meters: f64, sys: System) -> String {
+    match sys {
+        System::Metric => format!("{meters:.2} m"),
+        System::Imperial => format!("{:.2} ft", meters * 3.281),
+    }
+}
+
+//The outer `Result` is matched first, then the `Ok` arm produces the inner `Result` from parsing.
+// The `Err` arm passes the original error along untouched.
+fn load_and_parse(raw: Result<String, String>) -> Result<i32, String> {
+    match raw {
+        Ok(s) => s.parse().map_err(|_| "bad number".to_string()),
+        Err(e) => Err(e),
+    }
+}
+
+enum RedirectKind {
+    None,
+    Out(String),
+    Append(String),
+    In(String),
+}
+
+//The `..` at the start of each pattern skips over the command and its arguments, so only the last
+// two tokens are looked at.
+fn parse_redirect(tokens: &[&str]) -> RedirectKind {
+    match tokens {
+        [.., ">", file] => RedirectKind::Out(file.to_string()),
+        [.., ">>", file] => RedirectKind::Append(file.to_string()),
+        [.., "<", file] => RedirectKind::In(file.to_string()),
+        _ => RedirectKind::None,
+    }
+}
+
+enum Weekday {
+    Mon,
+    Tue,
+    Wed,
+    Thu,
+    Fri,
+    Sat,
+    Sun,
+}
+
+//Range patterns are only allowed for chars and numbers, so `Weekday::Mon..=Weekday::Thu` does not
+// compile. Adding `#[repr(u8)]` and matching on `*d as u8` with `0..=3` would work, but then the
+// compiler can no longer check that every day is covered. Listing the variants with `|` keeps it
+// exhaustive.
+fn working_hours(d: &Weekday) -> u8 {
+    match d {
+        Weekday::Mon | Weekday::Tue | Weekday::Wed | Weekday::Thu => 8,
+        Weekday::Fri => 6,
+        Weekday::Sat | Weekday::Sun => 0,
+    }
+}
+
+//The range arms decide which alphabet the char is rotated within, everything else is left alone.
+// Decrypting is the same as encrypting with the rest of the alphabet, `26 - shift`.
+fn caesar(text: &str, shift: u8) -> String {
+    let shift = shift % 26;
+
+    text.chars()
+        .map(|c| match c {
+            'a'..='z' => ((c as u8 - b'a' + shift) % 26 + b'a') as char,
+            'A'..='Z' => ((c as u8 - b'A' + shift) % 26 + b'A') as char,
+            other => other,
+        })
+        .collect()
+}
+
+//Patterns nest as deep as the data does. Here `Some(((x1, y1), (x2, y2)))` reaches through the
+// `Option` and both tuples at once to name all four coordinates. The coordinates are turned into
+// `f64` before subtracting so that points far apart can not overflow an `i32`.
+#[allow(clippy::manual_map)]
+fn distance(opt: Option<((i32, i32), (i32, i32))>) -> Option<f64> {
+    match opt {
+        Some(((x1, y1), (x2, y2))) => {
+            let dx = f64::from(x2) - f64::from(x1);
+            let dy = f64::from(y2) - f64::from(y1);
+            Some((dx * dx + dy * dy).sqrt())
+        }
+        None => None,
+    }
+}
+
+enum Compression {
+    None,
+    Fast,
+    Best,
+    Custom(u8),
+}
+
+//The first `Custom` arm only matches levels 0 through 9 and binds the level. Any other level falls
+// through to the second `Custom` arm.
+fn strategy(c: &Compression) -> String {
+    match c {
+        Compression::None => "store".into(),
+        Compression::Fast => "fast".into(),
+        Compression::Best => "max".into(),
+        Compression::Custom(lvl @ 0..=9) => format!("level {lvl}"),
+        Compression::Custom(_) => "invalid".into(),
+    }
+}
+
+fn to_u8(n: i64) -> Result<u8, String> {
+    match u8::try_from(n) {
+        Ok(v) => Ok(v),
+        Err(_) => Err(format!("{n} out of u8 range")),
+    }
+}
+
+enum Json {
+    Null,
+    Bool(bool),
+    Num(f64),
+    Str(String),
+}
+
+//`if let` is the simplest way to pick out one variant and skip all of the others.
+fn collect_numbers(items: &[Json]) -> Vec<f64> {
+    let mut out = Vec::new();
+    for item in items {
+        if let Json::Num(n) = item {
+            out.push(*n);
+        }
+    }
+    out
+}
+
+//`zip` would quietly stop at the end of the shorter slice, so the guard rejects mismatched lengths
+// before any multiplying happens. `try_fold` stops with `None` as soon as a product or the running
+// total overflows.
+fn dot_product(a: &[i32], b: &[i32]) -> Option<i32> {
+    match (a.len(), b.len()) {
+        (la, lb) if la == lb => a
+            .iter()
+            .zip(b)
+            .try_fold(0i32, |total, (x, y)| total.checked_add(x.checked_mul(*y)?)),
+        _ => None,
+    }
+}
+
+//Both of the first two arms match `Invalid`, the guard is what decides between them. When the
+// guard is false the match moves on to the next arm instead of giving up.
+fn root_message(e: &AppError) -> String {
+    match e {
+        AppError::Invalid(msg) if msg.contains("nested") => format!("nested: {msg}"),
+        AppError::Invalid(msg) => msg.clone(),
+        other => format!("{other:?}"),
+    }
+}
+
+//Anything over 100% is clamped to a full bar instead of overflowing it.
+fn bar_fill(percent: u8, width: usize) -> usize {
+    match percent {
+        0..=100 => percent as usize * width / 100,
+        _ => width,
+    }
+}
+
+//Slicing with `&s[..n]` panics when `n` is past the end or lands in the middle of a multi-byte
+// char, like 2 in "héllo". `get` returns `None` in both of those cases instead.
+fn safe_prefix(s: &str, n: usize) -> &str {
+    match s.get(..n) {
+        Some(p) => p,
+        None => s,
+    }
+}
+
+enum TriangleKey {
+    Base,
+    Height,
+    Area,
+}
+
+//Each arm sorts by a different field. The area is compared as `base * height` because halving
+// both sides would not change which triangle is bigger.
+fn sort_triangles(ts: &mut [Triangle], key: TriangleKey) {
+    match key {
+        TriangleKey::Base => ts.sort_by_key(|t| t.base),
+        TriangleKey::Height => ts.sort_by_key(|t| t.height),
+        TriangleKey::Area => ts.sort_by_key(|t| t.base * t.height),
+    }
+}
+
+//A closing parenthesis with nothing open means the string can never be balanced, so that returns
+// early instead of letting the depth go negative.
+fn find_balanced(s: &str) -> bool {
+    let mut depth = 0usize;
+
+    for c in s.chars() {
+        match c {
+            '(' => depth += 1,
+            ')' => {
+                if depth == 0 {
+                    return false;
+                }
+                depth -= 1;
+            }
+            _ => {}
+        }
+    }
+
+    depth == 0
+}
+
+//The axis literal and the point's destructuring are done in the same pattern. The last arm binds
+// the whole point as `p` so it can be returned unchanged.
+fn reflect(point: (i32, i32), axis: char) -> (i32, i32) {
+    match (axis, point) {
+        ('x', (x, y)) => (x, -y),
+        ('y', (x, y)) => (-x, y),
+        (_, p) => p,
+    }
+}
+
+enum CacheResult<T> {
+    Hit(T),
+    Miss,
+    Stale(T),
+}
+
+//A stale value is still used rather than waiting on a refetch, only a miss needs the fallback.
+fn use_or_refetch<T>(r: CacheResult<T>, fallback: T) -> T {
+    match r {
+        CacheResult::Hit(v) => v,
+        CacheResult::Stale(v) => v,
+        CacheResult::Miss => fallback,
+    }
+}
+
+//`prev` starts as `None` so the first byte always starts a new run. After that the guard decides
+// whether the current byte extends the run or resets it.
+fn longest_run(bytes: &[u8]) -> usize {
+    let mut prev = None;
+    let mut run = 0;
+    let mut longest = 0;
+
+    for &current in bytes {
+        match (prev, current) {
+            (Some(p), c) if p == c => run += 1,
+            _ => run = 1,
+        }
+        longest = longest.max(run);
+        prev = Some(current);
+    }
+
+    longest
+}
+
+//The `--no-` arm has to come first because every `--no-` flag also starts with `--`. A bare `--`
+// or `--no-` has no name after the prefix, so those fall through to `None`.
+fn parse_flag(arg: &str) -> Option<(String, bool)> {
+    match arg {
+        a if a.starts_with("--no-") && a.len() > 5 => Some((a[5..].to_string(), false)),
+        a if a.starts_with("--") && a.len() > 2 && a != "--no-" => Some((a[2..].to_string(), true)),
+        _ => None,
+    }
+}
+
+//Enums from the standard library are matched the same way as the ones defined here.
+fn ip_version(addr: std::net::IpAddr) -> u8 {
+    use std::net::IpAddr;
+
+    match addr {
+        IpAddr::V4(_) => 4,
+        IpAddr::V6(_) => 6,
+    }
+}
+
+//Two equal dice always add up to an even number, so a roll can never be both a win and doubles.
+// That means the order of the first two arms does not matter here, but it would if the rules did.
+// The dice are widened to `u16` before matching so that adding two large values can not overflow.
+fn roll_outcome(die1: u8, die2: u8) -> &'static str {
+    match (u16::from(die1), u16::from(die2)) {
+        (a, b) if a + b == 7 || a + b == 11 => "win",
+        (a, b) if a == b => "doubles",
+        _ => "roll again",
+    }
+}
+
+//`char::from_u32` already returns `None` for surrogates and values past the end of unicode. The
+// match only passes that result along, which clippy flags, but it shows both cases by name.
+#[allow(clippy::needless_match, clippy::manual_map)]
+fn codepoint_to_char(cp: u32) -> Option<char> {
+    match char::from_u32(cp) {
+        Some(c) => Some(c),
+        None => None,
+    }
+}
+
+//The surrogate range is inside of the unicode range, so it has to be checked before it.
+fn codepoint_kind(cp: u32) -> &'static str {
+    match cp {
+        0..=0x7F => "ascii",
+        0xD800..=0xDFFF => "surrogate",
+        0x80..=0x10FFFF => "unicode",
+        _ => "out of range",
+    }
+}
+
+//The literal `0` in either position handles leaving that part out of the sentence.
+fn amount_in_words(cents: u64) -> String {
+    match (cents / 100, cents % 100) {
+        (0, 0) => "nothing".into(),
+        (d, 0) => format!("{d} dollars"),
+        (0, c) => format!("{c} cents"),
+        (d, c) => format!("{d} dollars and {c} cents"),
+    }
+}
+
+struct LinkedNode {
+    value: i32,
+    next: Option<Box<LinkedNode>>,
+}
+
+//Matching on `&node.next` borrows the next node instead of moving it out. `n` is a
+// `&Box<LinkedNode>` which derefs to a `&LinkedNode` for the recursive call.
+fn sum_list(node: &LinkedNode) -> i32 {
+    match &node.next {
+        Some(n) => node.value + sum_list(n),
+        None => node.value,
+    }
+}
+
+fn greeting(hour: u8) -> &'static str {
+    match hour {
+        0..=11 => "good morning",
+        12..=17 => "good afternoon",
+        18..=23 => "good evening",
+        _ => "invalid hour",
+    }
+}
+
+//The overlap on each axis is how far the two screens share that axis. A negative overlap on either
+// axis means there is a gap between them, and zero means they only share an edge.
+fn overlap_kind(a: &Screen, b: &Screen) -> &'static str {
+    use std::cmp::Ordering::{Equal, Less};
+
+    let overlap_x = (a.x + a.size).min(b.x + b.size) - a.x.max(b.x);
+    let overlap_y = (a.y + a.size).min(b.y + b.size) - a.y.max(b.y);
+
+    match (overlap_x.cmp(&0), overlap_y.cmp(&0)) {
+        (Less, _) | (_, Less) => "disjoint",
+        (Equal, _) | (_, Equal) => "touching",
+        _ => "overlapping",
+    }
+}
+
+//Each direction accepts both the lower and upper case key with an or-pattern.
+fn parse_move(c: char) -> Option<Dir> {
+    match c {
+        'w' | 'W' => Some(Dir::N),
+        's' | 'S' => Some(Dir::S),
+        'a' | 'A' => Some(Dir::W),
+        'd' | 'D' => Some(Dir::E),
+        _ => None,
+    }
+}
+
+//The literals pick out the days that have their own names, and the guard splits everything else
+// into the future and the past.
+fn relative_label(days: i64) -> String {
+    match days {
+        0 => "today".into(),
+        1 => "tomorrow".into(),
+        -1 => "yesterday".into(),
+        d if d > 0 => format!("in {d} days"),
+        d => format!("{} days ago", d.unsigned_abs()),
+    }
+}
+
+//`splitn(2, ...)` only splits on the first `=`, so a value can still have an `=` in it. A line
+// without any `=` only has one part and does not match `[k, v]`, so it is skipped.
+fn parse_env_block(lines: &[&str]) -> std::collections::HashMap<String, String> {
+    let mut env = std::collections::HashMap::new();
+
+    for line in lines {
+        let parts: Vec<&str> = line.splitn(2, '=').collect();
+        if let [k, v] = parts.as_slice() {
+            if !k.trim().is_empty() {
+                env.insert(k.trim().to_string(), v.trim().to_string());
+            }
+        }
+    }
+
+    env
+}
+
+//Matching on `&Shape` makes the struct variant fields references, which is why the rectangle needs
+// `*width` while the circle can multiply `radius` directly since `&isize * isize` is implemented.
+fn bounding_size(s: &Shape) -> (isize, isize) {
+    match s {
+        Shape::Triangle(t) => (t.base, t.height),
+        Shape::Rectangle { width, height } => (*width, *height),
+        Shape::Circle { radius } => (radius * 2, radius * 2),
+    }
+}
+
+enum Vote {
+    Yes,
+    No,
+    Abstain,
+}
+
+//The counts are carried through the fold as a tuple, and the closure destructures it right in its
+// parameters so each arm only has to bump its own count.
+fn tally(votes: &[Vote]) -> (usize, usize, usize) {
+    votes.iter().fold((0, 0, 0), |(yes, no, abstain), vote| match vote {
+        Vote::Yes => (yes + 1, no, abstain),
+        Vote::No => (yes, no + 1, abstain),
+        Vote::Abstain => (yes, no, abstain + 1),
+    })
+}
+
+//`d @ ..` binds the whole slice so the guard can check its length before the arm uses it. The
+// digits are weighted from 10 down to 1 and a valid ISBN adds up to a multiple of 11. Only the last
+// one, the check digit, is allowed to be 10, which is written as `X`.
+#[allow(clippy::redundant_at_rest_pattern)]
+fn isbn10_valid(digits: &[u8]) -> bool {
+    match digits {
+        [d @ ..] if d.len() == 10 => {
+            let valid_digits = d[..9].iter().all(|&n| n <= 9) && d[9] <= 10;
+            let sum: u32 = d.iter().enumerate().map(|(i, &n)| (10 - i as u32) * n as u32).sum();
+            valid_digits && sum.is_multiple_of(11)
+        }
+        _ => false,
+    }
+}
+
+
+//`lock` returns an `Err` when a thread panicked while holding the lock. The error still holds the
+// guard though, so `into_inner` can be used to get at the data anyway instead of panicking again.
+fn with_locked(data: &std::sync::Mutex<i32>) -> i32 {
+    match data.lock() {
+        Ok(guard) => *guard,
+        Err(poisoned) => *poisoned.into_inner(),
+    }
+}
+
+//A string literal and a binding can be mixed in the same tuple pattern.
+fn timer_millis(value: u64, unit: &str) -> Option<u64> {
+    match (value, unit) {
+        (v, "ms") => Some(v),
+        (v, "s") => v.checked_mul(1000),
+        (v, "m") => v.checked_mul(60000),
+        _ => None,
+    }
+}
+
+//The tuple from `bounding_size` is destructured in the pattern and the guard compares both sides
+// against the screen's size. Clippy would prefer `matches!`, but the full match shows the guard.
+#[allow(clippy::match_like_matches_macro)]
+fn fits(shape: &Shape, screen: &Screen) -> bool {
+    match bounding_size(shape) {
+        (w, h) if w <= screen.size && h <= screen.size => true,
+        _ => false,
+    }
+}
+
+
+//The guard can compare bindings that came from two different structs in the same tuple. When the
+// first guard fails the second arm destructures the same pair again, this time by height.
+fn triangle_relation(a: &Triangle, b: &Triangle) -> &'static str {
+    match (a, b) {
+        (Triangle { base: ba, .. }, Triangle { base: bb, .. }) if ba == bb => "same base",
+        (Triangle { height: ha, .. }, Triangle { height: hb, .. }) if ha == hb => "same height",
+        _ => "different",
+    }
+}
+
+#[derive(Debug)]
+enum Tri {
+    Yes,
+    No,
+    Maybe,
+}
+
+fn tristate(s: &str) -> Tri {
+    match s {
+        "yes" | "y" => Tri::Yes,
+        "no" | "n" => Tri::No,
+        _ => Tri::Maybe,
+    }
+}
+
+//`windows(3)` always gives slices of exactly three elements, but the compiler does not know that,
+// so the `_` arm is still needed. The peak is the middle of the window, one past where it starts.
+fn local_maxima(nums: &[i32]) -> Vec<usize> {
+    nums.windows(3)
+        .enumerate()
+        .filter_map(|(i, window)| match window {
+            [a, b, c] if b > a && b > c => Some(i + 1),
+            _ => None,
+        })
+        .collect()
+}
+
+//The tuple is destructured into one binding per flag, and then each flag is matched on its own to
+// pick either its letter or a `-`.
+#[allow(clippy::match_single_binding)]
+fn perm_string(read: bool, write: bool, execute: bool) -> String {
+    let flag = |set: bool, letter: char| match set {
+        true => letter,
+        false => '-',
+    };
+
+    match (read, write, execute) {
+        (r, w, x) => [flag(r, 'r'), flag(w, 'w'), flag(x, 'x')].iter().collect(),
+    }
+}
+
+
+enum Precision {
+    Integer,
+    Fixed(u8),
+    Scientific,
+}
+
+//`{:.*}` takes the number of decimal places as an extra argument before the value. More than 10
+// places is not supported, so those fall through to the default formatting.
+fn format_number(n: f64, p: Precision) -> String {
+    match p {
+        Precision::Integer => format!("{}", n as i64),
+        Precision::Fixed(d @ 0..=10) => format!("{:.*}", d as usize, n),
+        Precision::Fixed(_) => format!("{n}"),
+        Precision::Scientific => format!("{n:e}"),
+    }
+}
+
+#[derive(Debug)]
+enum Tile {
+    Wall,
+    Floor,
+    Goal,
+}
+
+fn can_enter(tile: &Tile) -> bool {
+    match tile {
+        Tile::Wall => false,
+        Tile::Floor | Tile::Goal => true,
+    }
+}
+
+//`matches!` is shorthand for a match with one arm that returns true and a `_` arm that returns
+// false.
+fn is_goal(tile: &Tile) -> bool {
+    matches!(tile, Tile::Goal)
+}
+
+//`recv` blocks until there is a value and returns an `Err` once every sender has been dropped, so
+// the `while let` ends exactly when there is nothing left to receive.
+fn drain_channel(rx: std::sync::mpsc::Receiver<i32>) -> Vec<i32> {
+    let mut collected = Vec::new();
+    while let Ok(v) = rx.recv() {
+        collected.push(v);
+    }
+    collected
+}
+
+fn parse_coordinate(s: &str) -> Option<(isize, isize)> {
+    let parts: Vec<&str> = s.split(',').collect();
+    match parts.as_slice() {
+        [x, y] => Some((x.trim().parse().ok()?, y.trim().parse().ok()?)),
+        _ => None,
+    }
+}
+
+//A point on the edge of the screen still counts as being on it, only `Outside` is an error.
+fn place_on_screen(screen: &Screen, coord: &str) -> Result<(isize, isize), String> {
+    let Some((x, y)) = parse_coordinate(coord) else {
+        return Err(format!("'{coord}' is not a coordinate"));
+    };
+
+    match screen.locate(x, y) {
+        PointResult::Inside | PointResult::OnEdge => Ok((x, y)),
+        PointResult::Outside => Err(format!("({x}, {y}) is outside of the screen")),
+    }
+}
+
+//`peek` gives a reference to the largest value, so `&p` in the pattern copies it out for the guard.
+fn top_priority(heap: &std::collections::BinaryHeap<i32>) -> &'static str {
+    match heap.peek() {
+        Some(&p) if p > 100 => "urgent",
+        Some(_) => "normal",
+        None => "empty",
+    }
+}
+
+//The number ends at the first char that is not a digit and the rest of the string is the suffix.
+fn parse_bytes(s: &str) -> Option<u64> {
+    let split = s.find(|c: char| !c.is_ascii_digit()).unwrap_or(s.len());
+    let (digits, suffix) = s.split_at(split);
+    let bytes: u64 = digits.parse().ok()?;
+
+    match suffix {
+        "B" => Some(bytes),
+        "KB" => bytes.checked_mul(1024),
+        "MB" => bytes.checked_mul(1024 * 1024),
+        _ => None,
+    }
+}
+
+enum Align {
+    Left,
+    Right,
+    Center,
+}
+
+//The `width$` in the format spec uses the `width` variable as the width to pad to.
+fn pad(s: &str, width: usize, align: Align) -> String {
+    match align {
+        Align::Left => format!("{s:<width$}"),
+        Align::Right => format!("{s:>width$}"),
+        Align::Center => format!("{s:^width$}"),
+    }
+}
+
+//Scales every dimension by `numerator / denominator`. The multiply happens first so that doubling
+// is exact, and dividing rounds down since the dimensions are integers.
+fn scale_shape(s: &Shape, numerator: isize, denominator: isize) -> Shape {
+    let scale = |v: isize| v * numerator / denominator;
+
+    match s {
+        Shape::Triangle(t) => Shape::Triangle(Triangle {
+            base: scale(t.base),
+            height: scale(t.height),
+        }),
+        Shape::Rectangle { width, height } => Shape::Rectangle {
+            width: scale(*width),
+            height: scale(*height),
+        },
+        Shape::Circle { radius } => Shape::Circle { radius: scale(*radius) },
+    }
+}
+
+//The shape is moved into the tuple along with the command. An unknown command hands back the same
+// shape that was passed in through the `shape` binding.
+fn transform_shape(s: Shape, cmd: &str) -> Shape {
+    match (s, cmd) {
+        (shape, "double") => scale_shape(&shape, 2, 1),
+        (shape, "halve") => scale_shape(&shape, 1, 2),
+        (shape, _) => shape,
+    }
+}
+
+//Hardcore overrides the level so it comes first. Level 0 is not in any of the ranges, so it ends up
+// as "hard" along with everything above 7.
+fn difficulty(level: u8, hardcore: bool) -> &'static str {
+    match (level, hardcore) {
+        (_, true) => "nightmare",
+        (1..=3, false) => "easy",
+        (4..=7, false) => "medium",
+        (_, false) => "hard",
+    }
+}
+
+enum ConfigEntry {
+    KeyValue(String, String),
+    Comment(String),
+}
+
+fn parse_bool(s: &str) -> Option<bool> {
+    match s {
+        "true" | "yes" | "on" | "1" => Some(true),
+        "false" | "no" | "off" | "0" => Some(false),
+        _ => None,
+    }
+}
+
+//Only a key value entry can hold a bool. `map` turns the parsed bool into the pair, and a value
+// that is not a bool stays `None`.
+fn config_bool(entry: &ConfigEntry) -> Option<(String, bool)> {
+    match entry {
+        ConfigEntry::KeyValue(k, v) => parse_bool(v).map(|b| (k.clone(), b)),
+        _ => None,
+    }
+}
+
+//`Bound` is what range types use for their start and end. Matching all three variants covers every
+// kind of upper bound a range can have.
+fn describe_bound(b: std::ops::Bound<i32>) -> String {
+    use std::ops::Bound::{Excluded, Included, Unbounded};
+
+    match b {
+        Included(v) => format!("<= {v}"),
+        Excluded(v) => format!("< {v}"),
+        Unbounded => "unbounded".into(),
+    }
+}
+
+//Putting the `fits` result in the tuple with the shape means one `(false, _)` arm handles every
+// shape that is too big, and the rest of the arms only deal with shapes that fit.
+fn render_plan(shape: &Shape, screen: &Screen) -> String {
+    match (fits(shape, screen), shape) {
+        (false, _) => "shape too big".into(),
+        (true, Shape::Triangle(t)) => format!("draw triangle {}x{} centered", t.base, t.height),
+        (true, Shape::Rectangle { width, height }) => {
+            format!("draw rectangle {width}x{height} centered")
+        }
+        (true, Shape::Circle { radius }) => format!("draw circle radius {radius} centered"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parse_tlv_reads_well_formed_buffer() {
+        let buf = [
+            0x01, 0x00, 0x08, 0, 0, 0, 0, 0, 0, 0, 42,
+            0x02, 0x00, 0x02, b'h', b'i',
+            0x03, 0x00, 0x01, 0xFF,
+            0x7F, 0x00, 0x00,
+        ];
+        let records = parse_tlv(&buf).unwrap();
+
+        assert!(matches!(
+            records.as_slice(),
+            [
+                TlvRecord::Integer { tag: 0x01, value: 42 },
+                TlvRecord::Text { tag: 0x02, value: text },
+                TlvRecord::Bytes { tag: 0x03, data: bytes },
+                TlvRecord::Unknown { tag: 0x7F, length: 0, data: unknown },
+            ] if text == "hi" && bytes == &[0xFF] && unknown.is_empty()
+        ));
+    }
+
+    #[test]
+    fn parse_tlv_rejects_bad_records() {
+        assert!(matches!(parse_tlv(&[]), Ok(records) if records.is_empty()));
+        assert!(matches!(parse_tlv(&[0x01, 0x00]), Err(TlvError::Truncated)));
+        assert!(matches!(parse_tlv(&[0x02, 0x00, 0x05, b'h']), Err(TlvError::Truncated)));
+        assert!(matches!(parse_tlv(&[0x01, 0x00, 0x01, 7]), Err(TlvError::InvalidInteger)));
+        assert!(matches!(parse_tlv(&[0x02, 0x00, 0x01, 0xFF]), Err(TlvError::InvalidText)));
+    }
+
+    #[test]
+    fn run_command_matches_each_arm() {
+        assert_eq!(run_command(&["help"]), Ok("help text".to_string()));
+        assert_eq!(run_command(&["add", "2", "3"]), Ok("5".to_string()));
+        assert_eq!(run_command(&["add", "2", "x"]), Err("invalid number x".to_string()));
+        assert_eq!(run_command(&["echo", "a", "b"]), Ok("a b".to_string()));
+        assert_eq!(run_command(&["echo"]), Ok(String::new()));
+        assert_eq!(run_command(&["add", "2"]), Err("unknown add".to_string()));
+        assert_eq!(run_command(&["jump"]), Err("unknown jump".to_string()));
+        assert_eq!(run_command(&[]), Err("empty".to_string()));
+    }
+
+    #[test]
+    fn run_command_add_overflow() {
+        let max = i64::MAX.to_string();
+        assert_eq!(run_command(&["add", &max, "1"]), Err("overflow".to_string()));
+    }
+
+
+    #[test]
+    fn parse_frame_reads_minimal_frames() {
+        let buf = [0x81, 0x02, b'h', b'i', 0x82, 0x01, 0x07];
+        let Ok((WsFrame::Text(text), rest)) = parse_frame(&buf) else {
+            panic!("expected a text frame");
+        };
+        assert_eq!(text, "hi");
+        assert!(matches!(parse_frame(rest), Ok((WsFrame::Binary(data), [])) if data == [0x07]));
+
+        let close = [0x88, 0x04, 0x03, 0xE8, b'o', b'k'];
+        assert!(matches!(
+            parse_frame(&close),
+            Ok((WsFrame::Close(1000, reason), [])) if reason == "ok"
+        ));
+        assert!(matches!(parse_frame(&[0x89, 0x01, 0x05]), Ok((WsFrame::Ping(d), [])) if d == [5]));
+        assert!(matches!(parse_frame(&[0x8A, 0x00]), Ok((WsFrame::Pong(d), [])) if d.is_empty()));
+        assert!(matches!(
+            parse_frame(&[0x88, 0x00]),
+            Ok((WsFrame::Close(1005, reason), [])) if reason.is_empty()
+        ));
+
+    }
+
+    #[test]
+    fn parse_frame_rejects_bad_frames() {
+        assert!(matches!(parse_frame(&[0x81]), Err(WsError::TooShort)));
+        assert!(matches!(parse_frame(&[0x81, 0x03, b'h']), Err(WsError::TooShort)));
+        assert!(matches!(parse_frame(&[0x88, 0x01, 0x03]), Err(WsError::TooShort)));
+
+        assert!(matches!(parse_frame(&[0x83, 0x00]), Err(WsError::UnknownOpcode(0x3))));
+        assert!(matches!(parse_frame(&[0x81, 0x01, 0xFF]), Err(WsError::InvalidText)));
+    }
+
+    #[test]
+    fn parse_frame_rejects_unsupported_frames() {
+        let masked = [0x81, 0x82, 1, 2, 3, 4, b'h' ^ 1, b'i' ^ 2];
+        assert!(matches!(parse_frame(&masked), Err(WsError::Unsupported)));
+        assert!(matches!(parse_frame(&[0x82, 126, 0x00, 0x02, 1, 2]), Err(WsError::Unsupported)));
+        let extended = [0x82, 127, 0, 0, 0, 0, 0, 0, 0, 1, 1];
+        assert!(matches!(parse_frame(&extended), Err(WsError::Unsupported)));
+    }
+
+    #[test]
+    fn ip_class_boundaries() {
+        assert_eq!(ip_class(0), 'A');
+        assert_eq!(ip_class(127), 'A');
+        assert_eq!(ip_class(128), 'B');
+        assert_eq!(ip_class(191), 'B');
+        assert_eq!(ip_class(192), 'C');
+        assert_eq!(ip_class(223), 'C');
+        assert_eq!(ip_class(224), 'D');
+        assert_eq!(ip_class(239), 'D');
+        assert_eq!(ip_class(240), 'E');
+        assert_eq!(ip_class(255), 'E');
+    }
+
+    #[test]
+    fn label_signs_labels_each_sign() {
+        assert_eq!(label_signs(&[-1, 0, 1]), vec!["neg", "zero", "pos"]);
+    }
+
+    #[test]
+    fn config_builder_validates() {
+        let missing = ConfigBuilder::default().build();
+        assert_eq!(missing.err(), Some(vec!["host required"]));
+
+        let empty = ConfigBuilder { host: Some("  ".into()), ..Default::default() }.build();
+        assert_eq!(empty.err(), Some(vec!["host empty"]));
+
+        let valid = ConfigBuilder {
+            host: Some("localhost".into()),
+            port: Some(3000),
+            workers: Some(4),
+        };
+        let Ok(Config { host, port, workers }) = valid.build() else {
+            panic!("expected a valid config");
+        };
+        assert_eq!((host.as_str(), port, workers), ("localhost", 3000, 4));
+
+        let defaults = ConfigBuilder { host: Some("example.com".into()), ..Default::default() };
+        let Ok(Config { port, workers, .. }) = defaults.build() else {
+            panic!("expected a valid config");
+        };
+        assert_eq!((port, workers), (8080, 1));
+
+        let zero = ConfigBuilder { host: Some(" ".into()), workers: Some(0), ..Default::default() };
+        let errors = vec!["workers must be greater than zero", "host empty"];
+        assert_eq!(zero.build().err(), Some(errors));
+    }
+
+    #[test]
+    fn matches_each_operator() {
+        assert!(matches(&3, &QueryOp::Eq(3)));
+        assert!(!matches(&3, &QueryOp::NotEq(3)));
+        assert!(matches(&3, &QueryOp::Lt(4)));
+        assert!(!matches(&3, &QueryOp::Gt(4)));
+        assert!(matches(&3, &QueryOp::In(vec![1, 2, 3])));
+        assert!(!matches(&3, &QueryOp::Not(Box::new(QueryOp::Eq(3)))));
+    }
+
+    #[test]
+    fn matches_nested_and_or_over_triangle_bases() {
+        let triangles = [
+            Triangle { base: 1, height: 1 },
+            Triangle { base: 5, height: 1 },
+            Triangle { base: 8, height: 1 },
+            Triangle { base: 12, height: 1 },
+        ];
+        //(base > 2 && base < 10) || base == 12
+        let filter = QueryOp::Or(
+            Box::new(QueryOp::And(Box::new(QueryOp::Gt(2)), Box::new(QueryOp::Lt(10)))),
+            Box::new(QueryOp::Eq(12)),
+        );
+
+        let bases: Vec<isize> = triangles
+            .iter()
+            .filter(|t| matches(&t.base, &filter))
+            .map(|t| t.base)
+            .collect();
+        assert_eq!(bases, vec![5, 8, 12]);
+    }
+
+    #[test]
+    fn xor_table_all_combinations() {
+        assert!(!xor_table(false, false));
+        assert!(xor_table(false, true));
+        assert!(xor_table(true, false));
+        assert!(!xor_table(true, true));
+    }
+
+    #[test]
+    fn similar_triangles() {
+        assert!(similar(&Triangle { base: 2, height: 3 }, &Triangle { base: 4, height: 6 }));
+        assert!(!similar(&Triangle { base: 2, height: 3 }, &Triangle { base: 3, height: 2 }));
+    }
+
+    #[test]
+    fn similar_large_triangles() {
+        let big = Triangle { base: isize::MAX, height: isize::MAX };
+        assert!(similar(&big, &Triangle { base: 1, height: 1 }));
+        assert!(!similar(&big, &Triangle { base: isize::MAX, height: 1 }));
+    }
+
+
+    #[test]
+    fn arm_ordering_with_overlap() {
+        assert_eq!(demonstrate_arm_ordering(3), "low");
+        assert_eq!(demonstrate_arm_ordering(7), "low");
+        assert_eq!(demonstrate_arm_ordering(12), "medium-or-low");
+        assert_eq!(demonstrate_arm_ordering(18), "medium");
+        assert_eq!(demonstrate_arm_ordering(25), "high");
+    }
+
+    #[test]
+    fn arm_ordering_without_overlap() {
+        assert_eq!(correct_ordering(3), "low");
+        assert_eq!(correct_ordering(7), "medium-low");
+        assert_eq!(correct_ordering(12), "medium");
+        assert_eq!(correct_ordering(25), "high");
+    }
+
+    #[test]
+    fn parse_csv_line_simple_and_quoted() {
+        assert_eq!(parse_csv_line("a,b,c"), vec!["a", "b", "c"]);
+        assert_eq!(parse_csv_line(r#"x,"hello, world",y"#), vec!["x", "hello, world", "y"]);
+        assert_eq!(parse_csv_line(r#""say ""hi""",z"#), vec![r#"say "hi""#, "z"]);
+    }
+
+    #[test]
+    fn extract_or_default_some_and_none() {
+        assert_eq!(extract_or_default(Some("x".to_string()), "default"), "x");
+        assert_eq!(extract_or_default(None, "default"), "default");
+    }
+
+    #[test]
+    fn handle_ok_and_each_err() {
+        assert_eq!(handle(Ok(5)), Some(5));
+        assert_eq!(handle::<i32>(Err(AppError::NotFound)), None);
+        assert_eq!(handle::<i32>(Err(AppError::Invalid("bad".into()))), None);
+        assert_eq!(handle::<i32>(Err(AppError::Timeout)), None);
+    }
+
+    #[test]
+    fn word_counts_both_versions() {
+        let counts = word_counts("a b a c a");
+        assert_eq!(counts.len(), 3);
+        assert_eq!(counts["a"], 3);
+        assert_eq!(counts["b"], 1);
+        assert_eq!(counts["c"], 1);
+        assert_eq!(word_counts_entry_match("a b a c a"), counts);
+    }
+
+    #[test]
+    fn vec_state_empty_full_and_partial() {
+        let empty: Vec<i32> = Vec::with_capacity(4);
+        assert_eq!(vec_state(&empty), "empty");
+
+        let mut v = Vec::with_capacity(2);
+        v.push(1);
+        assert_eq!(vec_state(&v), "has room");
+        v.push(2);
+        v.shrink_to_fit();
+        assert_eq!(vec_state(&v), "full");
+    }
+
+    #[test]
+    fn get_mode_from_present_and_absent() {
+        assert_eq!(get_mode_from(Ok("debug".into())), "debug");
+        assert_eq!(get_mode_from(Err(std::env::VarError::NotPresent)), "default");
+    }
+
+    #[test]
+    fn simple_checksum_skips_zeros() {
+        assert_eq!(simple_checksum(&[]), 0);
+        assert_eq!(simple_checksum(&[1, 0, 2, 0, 3]), 6);
+        assert_eq!(simple_checksum(&[0, 0]), 0);
+        assert_eq!(simple_checksum(&[200, 0, 100]), 44);
+    }
+
+    #[test]
+    fn magnitude_bucket_values() {
+        assert_eq!(magnitude_bucket(0.0), "zero");
+        assert_eq!(magnitude_bucket(0.5), "fraction");
+        assert_eq!(magnitude_bucket(50.0), "small");
+        assert_eq!(magnitude_bucket(50000.0), "medium");
+        assert_eq!(magnitude_bucket(5e9), "large");
+    }
+
+    #[test]
+    fn classified_const_values() {
+        assert_eq!(CLASSIFIED, [-1, 0, 1]);
+    }
+
+    #[test]
+    fn apply_cmd_sequence() {
+        let mut screen = Screen::square(10);
+        apply_cmd(&mut screen, ScreenCmd::Resize(20));
+        apply_cmd(&mut screen, ScreenCmd::MoveBy(3, -2));
+        apply_cmd(&mut screen, ScreenCmd::MoveBy(1, 1));
+        assert_eq!((screen.size, screen.x, screen.y), (20, 4, -1));
+
+        apply_cmd(&mut screen, ScreenCmd::Reset);
+        assert_eq!((screen.size, screen.x, screen.y), (20, 0, 0));
+    }
+
+    #[test]
+    fn char_category_each_kind() {
+        assert_eq!(char_category('a'), "alpha");
+        assert_eq!(char_category('3'), "num");
+        assert_eq!(char_category(' '), "space");
+        assert_eq!(char_category('@'), "symbol");
+    }
+
+    #[test]
+    fn flatten_deeply_nested() {
+        let nested = Nested::List(vec![
+            Nested::Leaf(1),
+            Nested::List(vec![
+                Nested::Leaf(2),
+                Nested::List(vec![Nested::List(vec![Nested::Leaf(3)]), Nested::Leaf(4)]),
+            ]),
+            Nested::List(vec![]),
+            Nested::Leaf(5),
+        ]);
+        assert_eq!(flatten(&nested), vec![1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn length_of_boxed_and_owned() {
+        let empty_box: Box<str> = "".into();
+        let full_box: Box<str> = "hello".into();
+        let empty_string = String::new();
+        let full_string = String::from("hi");
+
+        assert_eq!(length_of(&empty_box), 0);
+        assert_eq!(length_of(&full_box), 5);
+        assert_eq!(length_of(&empty_string), 0);
+        assert_eq!(length_of(&full_string), 2);
+    }
+
+    #[test]
+    fn http_category_codes() {
+        assert_eq!(http_category(204), "ok-ish 204");
+        assert_eq!(http_category(301), "ok-ish 301");
+        assert_eq!(http_category(404), "error 404");
+        assert_eq!(http_category(100), "unknown 100");
+    }
+
+    #[test]
+    fn to_morse_letters_digits_and_unknown() {
+        assert_eq!(to_morse('a'), Some(".-"));
+        assert_eq!(to_morse('5'), Some("....."));
+        assert_eq!(to_morse('?'), None);
+    }
+
+    #[test]
+    fn is_palindrome_char_slices() {
+        let chars = |s: &str| s.chars().collect::<Vec<char>>();
+        assert!(is_palindrome(&chars("racecar")));
+        assert!(is_palindrome(&chars("abba")));
+        assert!(!is_palindrome(&chars("abc")));
+        assert!(is_palindrome(&[]));
+    }
+
+    #[test]
+    fn sorted_both_orders() {
+        assert_eq!(sorted(&[3, 1, 2], SortOrder::Asc), vec![1, 2, 3]);
+        assert_eq!(sorted(&[3, 1, 2], SortOrder::Desc), vec![3, 2, 1]);
+    }
+
+    #[test]
+    fn find_first_negative_index_with_and_without() {
+        assert_eq!(find_first_negative_index(&[3, 0, -2, -5]), Some(2));
+        assert_eq!(find_first_negative_index(&[1, 2, 3]), None);
+    }
+
+    #[test]
+    fn divisible_by_three_bits() {
+        assert!(divisible_by_three("110"));
+        assert!(!divisible_by_three("10"));
+        assert!(divisible_by_three(""));
+        assert!(divisible_by_three("1001"));
+        assert!(!divisible_by_three("12"));
+    }
+
+    #[test]
+    fn normalize_screen_clamps_negatives() {
+        let mut negative = Screen { x: -3, y: 4, ..Screen::square(10) };
+        normalize_screen(&mut negative);
+        assert_eq!((negative.x, negative.y), (0, 4));
+
+        let mut positive = Screen { x: 2, y: 5, ..Screen::square(10) };
+        normalize_screen(&mut positive);
+        assert_eq!((positive.x, positive.y), (2, 5));
+    }
+
+    #[test]
+    fn text_state_empty_blank_and_content() {
+        assert_eq!(text_state(""), "empty");
+        assert_eq!(text_state("   "), "blank");
+        assert_eq!(text_state("hi"), "content");
+    }
+
+    #[test]
+    fn encode_sextet_boundaries() {
+        assert_eq!(encode_sextet(0), 'A');
+        assert_eq!(encode_sextet(25), 'Z');
+        assert_eq!(encode_sextet(26), 'a');
+        assert_eq!(encode_sextet(52), '0');
+        assert_eq!(encode_sextet(62), '+');
+        assert_eq!(encode_sextet(63), '/');
+        assert_eq!(encode_sextet(64), '=');
+    }
+
+    #[test]
+    fn is_allowed_checks_allowlist() {
+        assert_eq!(is_allowed(2, &[1, 2, 3]), "allowed");
+        assert_eq!(is_allowed(9, &[1, 2, 3]), "denied");
+    }
+
+    #[test]
+    fn parse_duration_suffixes() {
+        assert_eq!(parse_duration("30s"), Some(30));
+        assert_eq!(parse_duration("5m"), Some(300));
+        assert_eq!(parse_duration("2h"), Some(7200));
+        assert_eq!(parse_duration("10x"), None);
+        assert_eq!(parse_duration(""), None);
+    }
+
+    #[test]
+    fn transition_valid_and_invalid() {
+        assert_eq!(transition(State::Idle, Event::Start), (State::Running, true));
+        assert_eq!(transition(State::Running, Event::Pause), (State::Paused, true));
+        assert_eq!(transition(State::Paused, Event::Resume), (State::Running, true));
+        assert_eq!(transition(State::Paused, Event::Stop), (State::Stopped, true));
+        assert_eq!(transition(State::Idle, Event::Pause), (State::Idle, false));
+        assert_eq!(transition(State::Running, Event::Resume), (State::Running, false));
+        assert_eq!(transition(State::Stopped, Event::Start), (State::Stopped, false));
+    }
+
+    #[test]
+    fn describe_range_value_each_range() {
+        assert_eq!(describe_range_value(5), "5 is single digit-ish");
+        assert_eq!(describe_range_value(50), "50 is double digit-ish");
+        assert_eq!(describe_range_value(500), "500 is big");
+    }
+
+    #[test]
+    fn top_words_repeated_text() {
+        let top = top_words("the cat and the dog and the bird", 2);
+        assert_eq!(top, vec![("the".to_string(), 3), ("and".to_string(), 2)]);
+        assert_eq!(top_words("b a", 5), vec![("a".to_string(), 1), ("b".to_string(), 1)]);
+    }
+
+    #[test]
+    fn describe_either_both_variants() {
+        assert_eq!(describe_either(&Either::Left("hello".into())), "text len 5");
+        assert_eq!(describe_either(&Either::Right(vec![1, 2, 3])), "vec len 3");
+    }
+
+    #[test]
+    fn locate_inside_edge_and_outside() {
+        let screen = Screen::square(10);
+        assert!(matches!(screen.locate(5, 5), PointResult::Inside));
+        assert!(matches!(screen.locate(0, 5), PointResult::OnEdge));
+        assert!(matches!(screen.locate(10, 10), PointResult::OnEdge));
+        assert!(matches!(screen.locate(11, 5), PointResult::Outside));
+        assert!(matches!(screen.locate(-1, -1), PointResult::Outside));
+    }
+
+    #[test]
+    fn combine_options_matches_nested_version() {
+        let pairs = [
+            (Some(1), Some(2)),
+            (Some(1), None),
+            (None, Some(2)),
+            (None, None),
+            (Some(i32::MAX), Some(1)),
+        ];
+        for (a, b) in pairs {
+            assert_eq!(combine_options(a, b), combine_options_nested(a, b));
+        }
+        assert_eq!(combine_options(Some(1), Some(2)), Some(3));
+        assert_eq!(combine_options(None, None), None);
+        assert_eq!(combine_options(Some(i32::MAX), Some(1)), None);
+
+    }
+
+    #[test]
+    fn compare_each_operator() {
+        assert!(!compare(CmpOp::Eq, 3, 5));
+        assert!(compare(CmpOp::Ne, 3, 5));
+        assert!(compare(CmpOp::Lt, 3, 5));
+        assert!(compare(CmpOp::Le, 3, 5));
+        assert!(!compare(CmpOp::Gt, 3, 5));
+        assert!(!compare(CmpOp::Ge, 3, 5));
+    }
+
+    #[test]
+    fn next_cell_survival_birth_and_death() {
+        assert!(matches!(next_cell(Cell::Alive, 2), Cell::Alive));
+        assert!(matches!(next_cell(Cell::Alive, 3), Cell::Alive));
+        assert!(matches!(next_cell(Cell::Dead, 3), Cell::Alive));
+        assert!(matches!(next_cell(Cell::Alive, 1), Cell::Dead));
+        assert!(matches!(next_cell(Cell::Alive, 4), Cell::Dead));
+        assert!(matches!(next_cell(Cell::Dead, 2), Cell::Dead));
+    }
+
+    #[test]
+    fn ascii_art_small_and_oversize() {
+        assert_eq!(ascii_art(&Triangle { base: 3, height: 3 }), "#\n##\n###\n");
+        assert_eq!(ascii_art(&Triangle { base: 2, height: 101 }), "too large");
+        assert_eq!(ascii_art(&Triangle { base: 101, height: 3 }), "too large");
+        assert_eq!(ascii_art(&Triangle { base: 1 << 40, height: 3 }), "too large");
+
+    }
+
+    #[test]
+    fn describe_vec_lengths() {
+        assert_eq!(describe_vec(&vec![]), "empty");
+        assert_eq!(describe_vec(&vec![1]), "single");
+        assert_eq!(describe_vec(&vec![1, 2]), "pair");
+        assert_eq!(describe_vec(&vec![1, 2, 3]), "many");
+    }
+
+    #[test]
+    fn eval_bool_nested() {
+        let expr = Bool::And(
+            Box::new(Bool::Lit(true)),
+            Box::new(Bool::Not(Box::new(Bool::Lit(false)))),
+        );
+        assert!(eval_bool(&expr));
+
+        let expr = Bool::Or(Box::new(Bool::Lit(false)), Box::new(Bool::Lit(false)));
+        assert!(!eval_bool(&expr));
+    }
+
+    #[test]
+    fn frame_empty_and_non_empty() {
+        assert_eq!(frame(&[]), None);
+        assert_eq!(frame(&[7]), Some((7, 7)));
+        assert_eq!(frame(&[1, 2, 3]), Some((1, 3)));
+    }
+
+    #[test]
+    fn number_kind_int_float_and_invalid() {
+        assert_eq!(number_kind("5"), "int");
+        assert_eq!(number_kind("5.5"), "float");
+        assert_eq!(number_kind("abc"), "invalid");
+    }
+
+    #[test]
+    fn decode_flags_masked_bits() {
+        assert_eq!(decode_flags(0b101), vec!["read", "execute"]);
+        assert_eq!(decode_flags(0b000), Vec::<&str>::new());
+        assert_eq!(decode_flags(0b111), vec!["read", "write", "execute"]);
+    }
+
+    #[test]
+    fn suggest_by_edit_distance() {
+        assert_eq!(edit_distance("pattern", "pattern"), 0);
+        assert_eq!(suggest(edit_distance("pattern", "pattern")), "exact");
+        assert_eq!(edit_distance("pattern", "patern"), 1);
+        assert_eq!(suggest(edit_distance("pattern", "patern")), "close");
+        assert_eq!(suggest(edit_distance("match", "matches")), "maybe");
+        assert_eq!(suggest(edit_distance("rust", "python")), "no match");
+    }
+
+    #[test]
+    fn parse_color_names() {
+        assert!(matches!(Color::parse("red"), Some(Color::Red)));
+        assert!(matches!(Color::parse("green"), Some(Color::Green)));
+        assert!(matches!(Color::parse("blue"), Some(Color::Blue)));
+        assert!(Color::parse("purple").is_none());
+    }
+
+    #[test]
+    fn dedup_adjacent_runs() {
+        assert_eq!(dedup_adjacent(&[1, 1, 2, 2, 2, 3]), vec![1, 2, 3]);
+        assert_eq!(dedup_adjacent(&[1, 2, 1]), vec![1, 2, 1]);
+    }
+
+    #[test]
+    fn compare_versions_pairs() {
+        use std::cmp::Ordering::{Equal, Greater, Less};
+
+        let v = |major, minor, patch| Version { major, minor, patch };
+        assert_eq!(compare_versions(&v(1, 2, 3), &v(1, 2, 3)), Equal);
+        assert_eq!(compare_versions(&v(1, 2, 3), &v(1, 2, 4)), Less);
+        assert_eq!(compare_versions(&v(1, 3, 0), &v(1, 2, 9)), Greater);
+        assert_eq!(compare_versions(&v(2, 0, 0), &v(1, 9, 9)), Greater);
+        assert_eq!(compare_versions(&v(0, 9, 9), &v(1, 0, 0)), Less);
+    }
+
+    #[test]
+    fn manual_sum_range() {
+        assert_eq!(manual_sum(1..=10), 55);
+        assert_eq!(manual_sum(std::iter::empty()), 0);
+    }
+
+    #[test]
+    fn tax_owed_bracket_boundaries() {
+        assert_eq!(tax_owed(0), 0);
+        assert_eq!(tax_owed(10000), 0);
+        assert_eq!(tax_owed(10001), 0);
+        assert_eq!(tax_owed(40000), 6000);
+        assert_eq!(tax_owed(40001), 6000);
+        assert_eq!(tax_owed(50000), 10000);
+    }
+
+    #[test]
+    fn minmax_empty_and_non_empty() {
+        assert_eq!(minmax(&[]), None);
+        assert_eq!(minmax(&[3, -1, 7, 2]), Some((-1, 7)));
+        assert_eq!(minmax(&[4]), Some((4, 4)));
+    }
+
+    #[test]
+    fn handle_key_edits_buffer() {
+        let mut buffer = String::new();
+        for key in [Key::Char('h'), Key::Char('i'), Key::Char('x'), Key::Backspace, Key::Enter] {
+            handle_key(&mut buffer, key);
+        }
+        assert_eq!(buffer, "hi\n");
+
+        handle_key(&mut buffer, Key::Arrow(Dir::W));
+        assert_eq!(buffer, "hi\n");
+
+        let mut empty = String::new();
+        handle_key(&mut empty, Key::Backspace);
+        assert_eq!(empty, "");
+    }
+
+    #[test]
+    fn status_code_each_variant() {
+        assert_eq!(status_code(&AppError::NotFound), 404);
+        assert_eq!(status_code(&AppError::Invalid("bad".into())), 422);
+        assert_eq!(status_code(&AppError::Timeout), 504);
+    }
+
+    #[test]
+    fn safe_index_bounds() {
+        assert_eq!(safe_index(&[10, 20, 30], 1), Some(20));
+        assert_eq!(safe_index(&[10, 20, 30], 3), None);
+    }
+
+    #[test]
+    fn eval_keywords_rpn() {
+        assert_eq!(eval_keywords(&["true", "false", "and"]), Some(false));
+        assert_eq!(eval_keywords(&["true", "false", "or"]), Some(true));
+        assert_eq!(eval_keywords(&["true", "true", "and", "false", "or"]), Some(true));
+        assert_eq!(eval_keywords(&["true", "and"]), None);
+        assert_eq!(eval_keywords(&["true", "true"]), None);
+        assert_eq!(eval_keywords(&["maybe"]), None);
+    }
+
+    #[test]
+    fn insert_position_present_and_absent() {
+        assert_eq!(insert_position(&[1, 3, 5, 7], 5), 2);
+        assert_eq!(insert_position(&[1, 3, 5, 7], 4), 2);
+        assert_eq!(insert_position(&[1, 3, 5, 7], 9), 4);
+    }
+
+    #[test]
+    fn congruent_same_and_mismatched_variants() {
+        let triangle = Shape::Triangle(Triangle { base: 3, height: 4 });
+        let rectangle = Shape::Rectangle { width: 2, height: 5 };
+
+        assert!(congruent(&triangle, &Shape::Triangle(Triangle { base: 3, height: 4 })));
+        assert!(congruent(&rectangle, &Shape::Rectangle { width: 5, height: 2 }));
+        assert!(congruent(&Shape::Circle { radius: 3 }, &Shape::Circle { radius: 3 }));
+        assert!(!congruent(&Shape::Circle { radius: 3 }, &Shape::Circle { radius: 4 }));
+        assert!(!congruent(&triangle, &rectangle));
+        assert!(!congruent(&Shape::Circle { radius: 2 }, &rectangle));
+    }
+
+    #[test]
+    fn ends_of_array() {
+        assert_eq!(ends([10, 20, 30, 40, 50]), (10, 50));
+    }
+
+    #[test]
+    fn schedule_combinations() {
+        assert_eq!(schedule(3, false), "sleep");
+        assert_eq!(schedule(3, true), "sleep");
+        assert_eq!(schedule(10, true), "relax");
+        assert_eq!(schedule(10, false), "work");
+        assert_eq!(schedule(20, false), "free");
+    }
+
+    #[test]
+    fn adjusted_adds_delta() {
+        let Triangle { base, height } = adjusted(Triangle { base: 5, height: 10 }, 2);
+        assert_eq!((base, height), (7, 12));
+    }
+
+    #[test]
+    fn before_delimiter_with_and_without() {
+        assert_eq!(before_delimiter("key=value", '='), "key");
+        assert_eq!(before_delimiter("no delimiter", '='), "no delimiter");
+    }
+
+    #[test]
+    fn migrate_name() {
+        assert!(matches!(migrate(ConfigV1::Name("app".into())), ConfigV2::Name(n) if n == "app"));
+    }
+
+    #[test]
+    fn medals_for_ranks() {
+        assert_eq!(medal(1), Some("gold"));
+        assert_eq!(medal(4), None);
+        assert_eq!(medals(&[1, 2, 3, 4]), vec![Some("gold"), Some("silver"), Some("bronze"), None]);
+    }
+
+    #[test]
+    fn within_timeout_below_equal_and_above() {
+        use std::time::Duration;
+
+        let limit = Duration::from_millis(100);
+        assert_eq!(within_timeout(Duration::from_millis(50), limit), "ok");
+        assert_eq!(within_timeout(Duration::from_millis(100), limit), "ok");
+        assert_eq!(within_timeout(Duration::from_millis(150), limit), "timeout");
+    }
+
+    #[test]
+    fn parse_hex_color_forms() {
+        assert_eq!(parse_hex_color("#ff0000"), Some((255, 0, 0)));
+        assert_eq!(parse_hex_color("00ff00"), Some((0, 255, 0)));
+        assert_eq!(parse_hex_color("#fff"), None);
+        assert_eq!(parse_hex_color("#ff00000"), None);
+        assert_eq!(parse_hex_color("#gg0000"), None);
+    }
+
+    #[test]
+    fn first_even_square_candidates() {
+        assert_eq!(first_even_square(&[1, 3, 4, 6]), Some(16));
+        assert_eq!(first_even_square(&[1, 3, 5]), None);
+        assert_eq!(first_even_square(&[]), None);
+        assert_eq!(first_even_square(&[50000, 2]), Some(4));
+    }
+
+    #[test]
+    fn format_length_both_systems() {
+        assert_eq!(format_length(2.0, System::Metric), "2.00 m");
+        assert_eq!(format_length(2.0, System::Imperial), "6.56 ft");
+    }
+
+    #[test]
+    fn load_and_parse_outer_and_inner() {
+        assert_eq!(load_and_parse(Ok("42".into())), Ok(42));
+        assert_eq!(load_and_parse(Ok("forty".into())), Err("bad number".into()));
+        assert_eq!(load_and_parse(Err("io error".into())), Err("io error".into()));
+    }
+
+    #[test]
+    fn parse_redirect_each_form() {
+        assert!(matches!(parse_redirect(&["ls", ">", "out"]), RedirectKind::Out(f) if f == "out"));
+        assert!(matches!(parse_redirect(&["ls", ">>", "l"]), RedirectKind::Append(f) if f == "l"));
+        assert!(matches!(parse_redirect(&["sort", "<", "in"]), RedirectKind::In(f) if f == "in"));
+        assert!(matches!(parse_redirect(&["ls", "-l"]), RedirectKind::None));
+    }
+
+    #[test]
+    fn working_hours_each_day() {
+        assert_eq!(working_hours(&Weekday::Mon), 8);
+        assert_eq!(working_hours(&Weekday::Tue), 8);
+        assert_eq!(working_hours(&Weekday::Wed), 8);
+        assert_eq!(working_hours(&Weekday::Thu), 8);
+        assert_eq!(working_hours(&Weekday::Fri), 6);
+        assert_eq!(working_hours(&Weekday::Sat), 0);
+        assert_eq!(working_hours(&Weekday::Sun), 0);
+    }
+
+    #[test]
+    fn caesar_round_trip() {
+        let encrypted = caesar("Hello, World!", 3);
+        assert_eq!(encrypted, "Khoor, Zruog!");
+        assert_eq!(caesar(&encrypted, 26 - 3), "Hello, World!");
+    }
+
+    #[test]
+    fn distance_some_and_none() {
+        assert_eq!(distance(Some(((0, 0), (3, 4)))), Some(5.0));
+        assert_eq!(distance(None), None);
+    }
+
+    #[test]
+    fn distance_large_coordinates() {
+        assert_eq!(distance(Some(((0, 0), (50000, 0)))), Some(50000.0));
+        let far = distance(Some(((i32::MIN, i32::MIN), (i32::MAX, i32::MAX)))).unwrap();
+        assert!((far - (u32::MAX as f64) * std::f64::consts::SQRT_2).abs() < 1.0);
+    }
+
+
+    #[test]
+    fn strategy_each_case() {
+        assert_eq!(strategy(&Compression::None), "store");
+        assert_eq!(strategy(&Compression::Fast), "fast");
+        assert_eq!(strategy(&Compression::Best), "max");
+        assert_eq!(strategy(&Compression::Custom(0)), "level 0");
+        assert_eq!(strategy(&Compression::Custom(9)), "level 9");
+        assert_eq!(strategy(&Compression::Custom(10)), "invalid");
+    }
+
+    #[test]
+    fn to_u8_in_and_out_of_range() {
+        assert_eq!(to_u8(200), Ok(200));
+        assert_eq!(to_u8(300), Err("300 out of u8 range".to_string()));
+        assert_eq!(to_u8(-1), Err("-1 out of u8 range".to_string()));
+    }
+
+    #[test]
+    fn collect_numbers_from_mixed_json() {
+        let items = [
+            Json::Num(1.5),
+            Json::Null,
+            Json::Str("2".into()),
+            Json::Bool(true),
+            Json::Num(-3.0),
+        ];
+        assert_eq!(collect_numbers(&items), vec![1.5, -3.0]);
+    }
+
+    #[test]
+    fn dot_product_equal_and_mismatched() {
+        assert_eq!(dot_product(&[1, 2, 3], &[4, 5, 6]), Some(32));
+        assert_eq!(dot_product(&[], &[]), Some(0));
+        assert_eq!(dot_product(&[1, 2], &[1]), None);
+    }
+
+    #[test]
+    fn dot_product_overflow() {
+        assert_eq!(dot_product(&[70000], &[70000]), None);
+        assert_eq!(dot_product(&[i32::MAX, 1], &[1, 1]), None);
+    }
+
+
+    #[test]
+    fn root_message_guarded_invalid() {
+        let nested = AppError::Invalid("nested field missing".into());
+        assert_eq!(root_message(&nested), "nested: nested field missing");
+        assert_eq!(root_message(&AppError::Invalid("bad input".into())), "bad input");
+        assert_eq!(root_message(&AppError::Timeout), "Timeout");
+        assert_eq!(root_message(&AppError::NotFound), "NotFound");
+    }
+
+    #[test]
+    fn bar_fill_percentages() {
+        assert_eq!(bar_fill(0, 20), 0);
+        assert_eq!(bar_fill(50, 20), 10);
+        assert_eq!(bar_fill(100, 20), 20);
+        assert_eq!(bar_fill(150, 20), 20);
+    }
+
+    #[test]
+    fn safe_prefix_boundaries() {
+        assert_eq!(safe_prefix("hello", 3), "hel");
+        assert_eq!(safe_prefix("hello", 10), "hello");
+        assert_eq!(safe_prefix("héllo", 2), "héllo");
+        assert_eq!(safe_prefix("héllo", 3), "hé");
+    }
+
+    #[test]
+    fn sort_triangles_by_each_key() {
+        let bases = |ts: &[Triangle]| ts.iter().map(|t| t.base).collect::<Vec<_>>();
+        let mut ts = vec![
+            Triangle { base: 3, height: 1 },
+            Triangle { base: 1, height: 5 },
+            Triangle { base: 2, height: 2 },
+        ];
+
+        sort_triangles(&mut ts, TriangleKey::Base);
+        assert_eq!(bases(&ts), vec![1, 2, 3]);
+        sort_triangles(&mut ts, TriangleKey::Height);
+        assert_eq!(bases(&ts), vec![3, 2, 1]);
+        sort_triangles(&mut ts, TriangleKey::Area);
+        assert_eq!(bases(&ts), vec![3, 2, 1]);
+
+        ts.push(Triangle { base: 10, height: 0 });
+        sort_triangles(&mut ts, TriangleKey::Area);
+        assert_eq!(bases(&ts), vec![10, 3, 2, 1]);
+    }
+
+    #[test]
+    fn find_balanced_parens() {
+        assert!(find_balanced("(())"));
+        assert!(!find_balanced("(()"));
+        assert!(!find_balanced(")("));
+        assert!(find_balanced(""));
+    }
+
+    #[test]
+    fn reflect_each_axis() {
+        assert_eq!(reflect((3, 4), 'x'), (3, -4));
+        assert_eq!(reflect((3, 4), 'y'), (-3, 4));
+        assert_eq!(reflect((3, 4), 'z'), (3, 4));
+    }
+
+    #[test]
+    fn use_or_refetch_each_variant() {
+        assert_eq!(use_or_refetch(CacheResult::Hit(1), 0), 1);
+        assert_eq!(use_or_refetch(CacheResult::Stale(2), 0), 2);
+        assert_eq!(use_or_refetch(CacheResult::Miss, 0), 0);
+    }
+
+    #[test]
+    fn longest_run_lengths() {
+        assert_eq!(longest_run(&[1, 1, 1, 2, 2]), 3);
+        assert_eq!(longest_run(&[]), 0);
+        assert_eq!(longest_run(&[1, 2, 2, 2, 2, 3]), 4);
+    }
+
+    #[test]
+    fn parse_flag_forms() {
+        assert_eq!(parse_flag("--verbose"), Some(("verbose".to_string(), true)));
+        assert_eq!(parse_flag("--no-color"), Some(("color".to_string(), false)));
+        assert_eq!(parse_flag("plain"), None);
+    }
+
+    #[test]
+    fn parse_flag_rejects_empty_names() {
+        assert_eq!(parse_flag("--"), None);
+        assert_eq!(parse_flag("--no-"), None);
+        assert_eq!(parse_flag("--no"), Some(("no".to_string(), true)));
+    }
+
+    #[test]
+    fn ip_version_v4_and_v6() {
+        use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+        assert_eq!(ip_version(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1))), 4);
+        assert_eq!(ip_version(IpAddr::V6(Ipv6Addr::LOCALHOST)), 6);
+    }
+
+    #[test]
+    fn roll_outcome_rolls() {
+        assert_eq!(roll_outcome(3, 4), "win");
+        assert_eq!(roll_outcome(5, 6), "win");
+        assert_eq!(roll_outcome(2, 2), "doubles");
+        assert_eq!(roll_outcome(1, 2), "roll again");
+        assert_eq!(roll_outcome(255, 255), "doubles");
+    }
+
+    #[test]
+    fn codepoint_valid_and_surrogate() {
+        assert_eq!(codepoint_to_char(0x41), Some('A'));
+        assert_eq!(codepoint_to_char(0x1F600), Some('😀'));
+        assert_eq!(codepoint_to_char(0xD800), None);
+        assert_eq!(codepoint_to_char(0x110000), None);
+    }
+
+    #[test]
+    fn codepoint_kind_ranges() {
+        assert_eq!(codepoint_kind(0x41), "ascii");
+        assert_eq!(codepoint_kind(0xE9), "unicode");
+        assert_eq!(codepoint_kind(0xD800), "surrogate");
+        assert_eq!(codepoint_kind(0xDFFF), "surrogate");
+        assert_eq!(codepoint_kind(0x110000), "out of range");
+    }
+
+    #[test]
+    fn amount_in_words_amounts() {
+        assert_eq!(amount_in_words(0), "nothing");
+        assert_eq!(amount_in_words(500), "5 dollars");
+        assert_eq!(amount_in_words(99), "99 cents");
+        assert_eq!(amount_in_words(1099), "10 dollars and 99 cents");
+    }
+
+    #[test]
+    fn sum_list_three_nodes() {
+        let list = LinkedNode {
+            value: 1,
+            next: Some(Box::new(LinkedNode {
+                value: 2,
+                next: Some(Box::new(LinkedNode { value: 3, next: None })),
+            })),
+        };
+        assert_eq!(sum_list(&list), 6);
+    }
+
+    #[test]
+    fn greeting_boundaries() {
+        assert_eq!(greeting(11), "good morning");
+        assert_eq!(greeting(12), "good afternoon");
+        assert_eq!(greeting(17), "good afternoon");
+        assert_eq!(greeting(18), "good evening");
+        assert_eq!(greeting(24), "invalid hour");
+    }
+
+    #[test]
+    fn overlap_kind_pairs() {
+        let base = Screen::square(10);
+        assert_eq!(overlap_kind(&base, &Screen { x: 20, y: 0, ..Screen::square(5) }), "disjoint");
+        assert_eq!(overlap_kind(&base, &Screen { x: 10, y: 2, ..Screen::square(5) }), "touching");
+        assert_eq!(overlap_kind(&base, &Screen { x: 8, y: 8, ..Screen::square(5) }), "overlapping");
+    }
+
+    #[test]
+    fn parse_move_each_key() {
+        assert!(matches!(parse_move('w'), Some(Dir::N)));
+        assert!(matches!(parse_move('W'), Some(Dir::N)));
+        assert!(matches!(parse_move('s'), Some(Dir::S)));
+        assert!(matches!(parse_move('S'), Some(Dir::S)));
+        assert!(matches!(parse_move('a'), Some(Dir::W)));
+        assert!(matches!(parse_move('A'), Some(Dir::W)));
+        assert!(matches!(parse_move('d'), Some(Dir::E)));
+        assert!(matches!(parse_move('D'), Some(Dir::E)));
+        assert!(parse_move('q').is_none());
+    }
+
+    #[test]
+    fn relative_label_days() {
+        assert_eq!(relative_label(-2), "2 days ago");
+        assert_eq!(relative_label(-1), "yesterday");
+        assert_eq!(relative_label(0), "today");
+        assert_eq!(relative_label(1), "tomorrow");
+        assert_eq!(relative_label(5), "in 5 days");
+        assert_eq!(relative_label(i64::MIN), "9223372036854775808 days ago");
+
+    }
+
+    #[test]
+    fn parse_env_block_skips_malformed_lines() {
+        let lines = ["HOST = localhost", "malformed", "URL=a=b", "=empty", "PORT=80"];
+        let env = parse_env_block(&lines);
+        assert_eq!(env.len(), 3);
+        assert_eq!(env["HOST"], "localhost");
+        assert_eq!(env["URL"], "a=b");
+        assert_eq!(env["PORT"], "80");
+    }
+
+    #[test]
+    fn bounding_size_each_variant() {
+        assert_eq!(bounding_size(&Shape::Triangle(Triangle { base: 3, height: 4 })), (3, 4));
+        assert_eq!(bounding_size(&Shape::Rectangle { width: 5, height: 2 }), (5, 2));
+        assert_eq!(bounding_size(&Shape::Circle { radius: 3 }), (6, 6));
+    }
+
+    #[test]
+    fn tally_mixed_ballot() {
+        let votes = [Vote::Yes, Vote::No, Vote::Yes, Vote::Abstain, Vote::Yes];
+        assert_eq!(tally(&votes), (3, 1, 1));
+    }
+
+    #[test]
+    fn isbn10_valid_and_invalid() {
+        assert!(isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 5, 2]));
+        assert!(isbn10_valid(&[0, 8, 0, 4, 4, 2, 9, 5, 7, 10]));
+        assert!(!isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 5, 3]));
+        assert!(!isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 5]));
+        assert!(!isbn10_valid(&[0, 3, 0, 6, 4, 0, 6, 1, 10, 2]));
+    }
+
+    #[test]
+    fn with_locked_reads_value() {
+        let data = std::sync::Mutex::new(7);
+        assert_eq!(with_locked(&data), 7);
+    }
+
+    #[test]
+    fn timer_millis_units() {
+        assert_eq!(timer_millis(5, "ms"), Some(5));
+        assert_eq!(timer_millis(5, "s"), Some(5000));
+        assert_eq!(timer_millis(5, "m"), Some(300000));
+        assert_eq!(timer_millis(5, "h"), None);
+    }
+
+    #[test]
+    fn fits_inside_screen() {
+        let screen = Screen::square(10);
+        assert!(fits(&Shape::Rectangle { width: 10, height: 3 }, &screen));
+        assert!(!fits(&Shape::Circle { radius: 8 }, &screen));
+    }
+
+    #[test]
+    fn triangle_relation_pairs() {
+        let t = |base, height| Triangle { base, height };
+        assert_eq!(triangle_relation(&t(3, 4), &t(3, 9)), "same base");
+        assert_eq!(triangle_relation(&t(2, 4), &t(3, 4)), "same height");
+        assert_eq!(triangle_relation(&t(2, 4), &t(3, 5)), "different");
+    }
+
+    #[test]
+    fn tristate_forms() {
+        assert!(matches!(tristate("yes"), Tri::Yes));
+        assert!(matches!(tristate("y"), Tri::Yes));
+        assert!(matches!(tristate("no"), Tri::No));
+        assert!(matches!(tristate("n"), Tri::No));
+        assert!(matches!(tristate("perhaps"), Tri::Maybe));
+    }
+
+    #[test]
+    fn local_maxima_peaks() {
+        assert_eq!(local_maxima(&[1, 3, 2, 5, 4]), vec![1, 3]);
+        assert_eq!(local_maxima(&[1, 2]), Vec::<usize>::new());
+    }
+
+    #[test]
+    fn perm_string_flags() {
+        assert_eq!(perm_string(true, false, true), "r-x");
+        assert_eq!(perm_string(true, true, true), "rwx");
+        assert_eq!(perm_string(false, false, false), "---");
+    }
+
+    #[test]
+    fn format_number_each_mode() {
+        assert_eq!(format_number(1.23456, Precision::Integer), "1");
+        assert_eq!(format_number(1.23456, Precision::Fixed(2)), "1.23");
+        assert_eq!(format_number(3.5, Precision::Fixed(11)), "3.5");
+        assert_eq!(format_number(1500.0, Precision::Scientific), "1.5e3");
+    }
+
+    #[test]
+    fn tiles_each_type() {
+        assert!(!can_enter(&Tile::Wall));
+        assert!(can_enter(&Tile::Floor));
+        assert!(can_enter(&Tile::Goal));
+        assert!(!is_goal(&Tile::Wall));
+        assert!(!is_goal(&Tile::Floor));
+        assert!(is_goal(&Tile::Goal));
+    }
+
+    #[test]
+    fn drain_channel_receives_everything() {
+        let (tx, rx) = std::sync::mpsc::channel();
+        let sender = std::thread::spawn(move || {
+            for v in [1, 2, 3] {
+                tx.send(v).unwrap();
+            }
+        });
+
+        assert_eq!(drain_channel(rx), vec![1, 2, 3]);
+        sender.join().unwrap();
+    }
+
+    #[test]
+    fn place_on_screen_points() {
+        let screen = Screen::square(10);
+        assert_eq!(place_on_screen(&screen, "3, 4"), Ok((3, 4)));
+        assert_eq!(place_on_screen(&screen, "10,0"), Ok((10, 0)));
+        assert_eq!(
+            place_on_screen(&screen, "12,4"),
+            Err("(12, 4) is outside of the screen".to_string())
+        );
+        assert_eq!(place_on_screen(&screen, "3;4"), Err("'3;4' is not a coordinate".to_string()));
+    }
+
+    #[test]
+    fn top_priority_heaps() {
+        use std::collections::BinaryHeap;
+
+        assert_eq!(top_priority(&BinaryHeap::new()), "empty");
+        assert_eq!(top_priority(&BinaryHeap::from([5, 20])), "normal");
+        assert_eq!(top_priority(&BinaryHeap::from([5, 200])), "urgent");
+    }
+
+    #[test]
+    fn parse_bytes_suffixes() {
+        assert_eq!(parse_bytes("10KB"), Some(10240));
+        assert_eq!(parse_bytes("2MB"), Some(2097152));
+        assert_eq!(parse_bytes("500B"), Some(500));
+        assert_eq!(parse_bytes("5GB"), None);
+    }
+
+    #[test]
+    fn pad_each_alignment() {
+        assert_eq!(pad("abc", 7, Align::Left), "abc    ");
+        assert_eq!(pad("abc", 7, Align::Right), "    abc");
+        assert_eq!(pad("abc", 7, Align::Center), "  abc  ");
+    }
+
+    #[test]
+    fn transform_shape_commands() {
+        let triangle = || Shape::Triangle(Triangle { base: 3, height: 5 });
+        assert!(matches!(
+            transform_shape(triangle(), "double"),
+            Shape::Triangle(Triangle { base: 6, height: 10 })
+        ));
+        assert!(matches!(
+            transform_shape(triangle(), "halve"),
+            Shape::Triangle(Triangle { base: 1, height: 2 })
+        ));
+        assert!(matches!(
+            transform_shape(triangle(), "spin"),
+            Shape::Triangle(Triangle { base: 3, height: 5 })
+        ));
+        assert!(matches!(
+            transform_shape(Shape::Circle { radius: 4 }, "double"),
+            Shape::Circle { radius: 8 }
+        ));
+    }
+
+    #[test]
+    fn difficulty_combinations() {
+        assert_eq!(difficulty(2, true), "nightmare");
+        assert_eq!(difficulty(2, false), "easy");
+        assert_eq!(difficulty(5, false), "medium");
+        assert_eq!(difficulty(9, false), "hard");
+        assert_eq!(difficulty(0, false), "hard");
+    }
+
+    #[test]
+    fn config_bool_entries() {
+        let entry = ConfigEntry::KeyValue("debug".into(), "yes".into());
+        assert_eq!(config_bool(&entry), Some(("debug".to_string(), true)));
+        let entry = ConfigEntry::KeyValue("name".into(), "app".into());
+        assert_eq!(config_bool(&entry), None);
+        assert_eq!(config_bool(&ConfigEntry::Comment("debug = true".into())), None);
+    }
+
+    #[test]
+    fn describe_bound_each_variant() {
+        use std::ops::Bound;
+
+        assert_eq!(describe_bound(Bound::Included(5)), "<= 5");
+        assert_eq!(describe_bound(Bound::Excluded(5)), "< 5");
+        assert_eq!(describe_bound(Bound::Unbounded), "unbounded");
+    }
+
+    #[test]
+    fn render_plan_fitting_and_oversized() {
+        let screen = Screen::square(10);
+        let triangle = Shape::Triangle(Triangle { base: 4, height: 6 });
+        assert_eq!(render_plan(&triangle, &screen), "draw triangle 4x6 centered");
+        assert_eq!(render_plan(&Shape::Circle { radius: 8 }, &screen), "shape too big");
+    }
+}